@@ -6,12 +6,24 @@ set -e
 # Define variables
 VERSION=$(grep '^version' Cargo.toml | cut -d '"' -f2)
 TARGET_BRANCH="gh-pages"
+DOMAIN="weiming.uga.edu"
 SOURCE_DIR="./target/dx/personal_website/release/web/public"
 TMP_DIR=$(mktemp -d)
 
+# Busy times for the meeting page, from the calendar's secret address, which is kept out of the repository
+if [ -n "$CALENDAR_FEED_URL" ]; then
+    echo "Downloading the calendar feed..."
+    curl -fsSL "$CALENDAR_FEED_URL" -o assets/calendar/busy.ics
+else
+    echo "CALENDAR_FEED_URL is not set, the meeting page will show no busy times."
+fi
+
 dx clean
 dx bundle --platform web
 
+# Put back the empty feed kept in the repository
+git checkout -- assets/calendar/busy.ics
+
 if [ ! -d "$SOURCE_DIR" ]; then
     echo "Error: SOURCE_DIR '$SOURCE_DIR' does not exist."
     exit 1
@@ -26,7 +38,10 @@ echo "Copying files from $SOURCE_DIR to $TARGET_BRANCH..."
 rm -rf $TMP_DIR/*
 
 cp -r $SOURCE_DIR/* $TMP_DIR/
-echo "weiming.uga.edu" > "$TMP_DIR/CNAME"
+echo "$DOMAIN" > "$TMP_DIR/CNAME"
+
+echo "Writing sitemap.xml..."
+cargo run --quiet --bin sitemap -- "https://$DOMAIN" > "$TMP_DIR/sitemap.xml"
 
 cd $TMP_DIR
 
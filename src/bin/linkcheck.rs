@@ -0,0 +1,69 @@
+// Checks every outbound link on the site and in the spreadsheets.
+//
+//     cargo run --bin linkcheck -- [--concurrency N] [--slow-ms N] [--timeout-ms N] [--skip-datasets] [--output FILE]
+//
+// Prints a JSON report and exits with 1 if any link is dead.
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::main]
+async fn main() {
+    use personal_website::components::{dataset_links, render_path, site_paths, Build};
+    use personal_website::linkcheck::*;
+    use std::time::Duration;
+
+    let mut options = CheckOptions::default();
+    let mut timeout = Duration::from_secs(15);
+    let mut skip_datasets = false;
+    let mut output: Option<String> = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().unwrap_or_else(|| fail(&format!("{} needs a value", name)));
+        match arg.as_str() {
+            "--concurrency" => options.concurrency = parse_number(&value("--concurrency")) as usize,
+            "--slow-ms" => options.slow_after = Duration::from_millis(parse_number(&value("--slow-ms"))),
+            "--timeout-ms" => timeout = Duration::from_millis(parse_number(&value("--timeout-ms"))),
+            "--skip-datasets" => skip_datasets = true,
+            "--output" => output = Some(value("--output")),
+            _ => fail(&format!("unknown argument {}", arg)),
+        }
+    }
+
+    let mut links = Vec::new();
+    for path in site_paths(Build::CURRENT) {
+        links.extend(extract_urls(&render_path(&path)).into_iter().map(|url| (path.clone(), url)));
+    }
+    if !skip_datasets {
+        links.extend(dataset_links().await);
+    }
+
+    let reports = check_links(&ReqwestClient::new(timeout), group_by_url(links), &options).await;
+    let report = report_json(&reports);
+    let text = serde_json::to_string_pretty(&report).expect("reports only hold plain values");
+    match output {
+        Some(path) => std::fs::write(&path, text).unwrap_or_else(|e| fail(&format!("cannot write {}: {}", path, e))),
+        None => println!("{}", text),
+    }
+
+    for r in reports.iter().filter(|r| matches!(r.status, LinkStatus::Dead { .. })) {
+        eprintln!("dead: {} ({})", r.url, r.sources.join(", "));
+    }
+    if report["dead"].as_u64() != Some(0) {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_number(s: &str) -> u64 {
+    s.parse().unwrap_or_else(|_| fail(&format!("{} is not a number", s)))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fail(message: &str) -> ! {
+    eprintln!("linkcheck: {}", message);
+    std::process::exit(2);
+}
+
+// The checker needs a native HTTP client and is not part of the web bundle
+#[cfg(target_arch = "wasm32")]
+fn main() {}
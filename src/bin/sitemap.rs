@@ -0,0 +1,18 @@
+// Writes the sitemap of the release site, with addresses under the given origin.
+//
+//     cargo run --bin sitemap -- https://weiming.uga.edu > sitemap.xml
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    use personal_website::components::{sitemap, Build};
+
+    let Some(base) = std::env::args().nth(1) else {
+        eprintln!("sitemap: give the site's origin, e.g. https://weiming.uga.edu");
+        std::process::exit(2);
+    };
+    print!("{}", sitemap(&base, Build::Release));
+}
+
+// Built with the native tools, not part of the web bundle
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,453 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::fmt;
+use std::sync::LazyLock;
+use super::page_cal::request::RequestSettings;
+use super::page_cal::rules::MeetingRules;
+
+// Page text lives in assets/content so wording changes do not touch the components.
+// The files are bundled at compile time and parsed on first use.
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct SocialLink {
+    pub(super) title: String,
+    // Font Awesome classes, e.g. "fa-brands fa-github"
+    pub(super) icon: String,
+    pub(super) url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Repository {
+    pub(super) name: String,
+    pub(super) description: String,
+    pub(super) language: String,
+    pub(super) url: String,
+    #[serde(default)]
+    pub(super) topics: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Repositories {
+    repositories: Vec<Repository>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct ResearchTheme {
+    pub(super) slug: String,
+    pub(super) title: String,
+    pub(super) image: String,
+    pub(super) alt: String,
+    pub(super) summary: String,
+    #[serde(default)]
+    pub(super) publications: Vec<String>,
+    #[serde(default)]
+    pub(super) keywords: Vec<String>,
+    #[serde(default)]
+    pub(super) repositories: Vec<String>,
+    #[serde(default)]
+    pub(super) members: Vec<String>,
+    // Grant ids from grants.toml
+    #[serde(default)]
+    pub(super) funding: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum Program {
+    Pi,
+    Phd,
+    Ms,
+    Undergrad,
+}
+
+impl Program {
+    // Degree an alumnus earned in the program
+    pub(super) fn degree(&self) -> &'static str {
+        match self {
+            Program::Pi => "Faculty",
+            Program::Phd => "PhD",
+            Program::Ms => "MS",
+            Program::Undergrad => "Undergraduate",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum MemberStatus {
+    Current,
+    Alumni,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Project {
+    pub(super) title: String,
+    pub(super) url: String,
+    pub(super) summary: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Thesis {
+    pub(super) title: String,
+    pub(super) url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Member {
+    pub(super) id: String,
+    pub(super) first_name: String,
+    pub(super) last_name: String,
+    pub(super) role: String,
+    pub(super) program: Program,
+    pub(super) status: MemberStatus,
+    pub(super) cohort: Option<i32>,
+    pub(super) photo: Option<String>,
+    pub(super) bio: String,
+    #[serde(default)]
+    pub(super) links: Vec<SocialLink>,
+    #[serde(default)]
+    author_names: Vec<String>,
+    #[serde(default)]
+    pub(super) projects: Vec<Project>,
+    // Alumni only
+    pub(super) graduation: Option<i32>,
+    pub(super) thesis: Option<Thesis>,
+    pub(super) placement: Option<String>,
+}
+
+impl Member {
+    // Spellings to look for in publication author lists
+    pub(super) fn author_names(&self) -> Vec<String> {
+        if !self.author_names.is_empty() {
+            return self.author_names.clone();
+        }
+        let initial = self.first_name.chars().next().unwrap_or_default();
+        vec![
+            format!("{} {}", self.first_name, self.last_name),
+            format!("{}. {}", initial, self.last_name),
+            format!("{}, {}.", self.last_name, initial),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Roster {
+    members: Vec<Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Grant {
+    pub(super) id: String,
+    pub(super) agency: String,
+    pub(super) award_number: String,
+    pub(super) title: String,
+    // Our role on the award, e.g. "PI" or "Co-PI"
+    pub(super) role: String,
+    pub(super) start: NaiveDate,
+    pub(super) end: NaiveDate,
+    // US dollars
+    pub(super) amount: Option<u64>,
+    pub(super) logo: Option<String>,
+    pub(super) url: Option<String>,
+}
+
+impl Grant {
+    pub(super) fn is_active(&self, today: NaiveDate) -> bool {
+        self.start <= today && today <= self.end
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Grants {
+    grants: Vec<Grant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum Season {
+    Spring,
+    Summer,
+    Fall,
+}
+
+impl Season {
+    pub(super) fn label(&self) -> &'static str {
+        match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Fall => "Fall",
+        }
+    }
+}
+
+// A semester such as "Fall 2025"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(try_from = "String")]
+pub(super) struct Term {
+    pub(super) year: i32,
+    pub(super) season: Season,
+}
+
+impl Term {
+    // First and last day of the term, roughly following the academic calendar
+    pub(super) fn dates(&self) -> (NaiveDate, NaiveDate) {
+        let (start, end) = match self.season {
+            Season::Spring => ((1, 1), (5, 31)),
+            Season::Summer => ((6, 1), (7, 31)),
+            Season::Fall => ((8, 1), (12, 31)),
+        };
+        let date = |(month, day)| NaiveDate::from_ymd_opt(self.year, month, day).expect("valid term date");
+        (date(start), date(end))
+    }
+}
+
+impl TryFrom<String> for Term {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("invalid term \"{}\", expected e.g. \"Fall 2025\"", text);
+        let (season, year) = text.trim().split_once(' ').ok_or_else(invalid)?;
+        let season = match season.to_lowercase().as_str() {
+            "spring" => Season::Spring,
+            "summer" => Season::Summer,
+            "fall" => Season::Fall,
+            _ => return Err(invalid()),
+        };
+        let year = year.trim().parse().map_err(|_| invalid())?;
+        Ok(Term { year, season })
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.season.label(), self.year)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Material {
+    pub(super) title: String,
+    pub(super) url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Course {
+    pub(super) code: String,
+    pub(super) title: String,
+    pub(super) terms: Vec<Term>,
+    pub(super) description: String,
+    pub(super) syllabus: Option<String>,
+    #[serde(default)]
+    pub(super) materials: Vec<Material>,
+}
+
+impl Course {
+    pub(super) fn latest_term(&self) -> Option<Term> {
+        self.terms.iter().max().copied()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Courses {
+    courses: Vec<Course>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Talk {
+    pub(super) date: NaiveDate,
+    pub(super) title: String,
+    pub(super) event: String,
+    pub(super) location: String,
+    // File name in assets/slides
+    pub(super) slides: Option<String>,
+    pub(super) video: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Talks {
+    talks: Vec<Talk>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct NewsItem {
+    pub(super) id: String,
+    pub(super) date: NaiveDate,
+    pub(super) title: String,
+    pub(super) body: String,
+    #[serde(default)]
+    pub(super) tags: Vec<String>,
+}
+
+impl NewsItem {
+    // First paragraph of the body, shown in lists
+    pub(super) fn summary(&self) -> &str {
+        let body = self.body.trim();
+        body.split("\n\n").next().unwrap_or(body)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct NewsFeed {
+    news: Vec<NewsItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct HomeContent {
+    pub(super) name: String,
+    pub(super) tagline: String,
+    pub(super) photo_alt: String,
+    pub(super) address: Vec<String>,
+    pub(super) greeting: String,
+    pub(super) bio: Vec<String>,
+    pub(super) recruiting: String,
+    pub(super) social: Vec<SocialLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct LabContent {
+    pub(super) about: Vec<String>,
+    pub(super) themes: Vec<ResearchTheme>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct AvailabilitySettings {
+    pub(super) feed: String,
+    pub(super) day_start: u32,
+    pub(super) day_end: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct BookingLink {
+    pub(super) label: String,
+    pub(super) url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct CalContent {
+    pub(super) title: String,
+    pub(super) instructions: Vec<String>,
+    pub(super) availability: AvailabilitySettings,
+    pub(super) rules: MeetingRules,
+    pub(super) request: RequestSettings,
+    pub(super) booking: Option<BookingLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(super) struct Page {
+    pub(super) name: String,
+    pub(super) label: String,
+    pub(super) icon: String,
+    // Listed in the navigation bar
+    #[serde(default)]
+    pub(super) nav: bool,
+    // Left out of release builds
+    #[serde(default)]
+    pub(super) hidden: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Pages {
+    pages: Vec<Page>,
+}
+
+fn load<T: for<'de> Deserialize<'de>>(name: &str, text: &str) -> T {
+    toml::from_str(text).unwrap_or_else(|e| panic!("assets/content/{} is invalid: {}", name, e))
+}
+
+static HOME: LazyLock<HomeContent> = LazyLock::new(|| load("home.toml", include_str!("../../assets/content/home.toml")));
+static LAB: LazyLock<LabContent> = LazyLock::new(|| load("lab.toml", include_str!("../../assets/content/lab.toml")));
+static MEMBERS: LazyLock<Vec<Member>> =
+    LazyLock::new(|| load::<Roster>("members.toml", include_str!("../../assets/content/members.toml")).members);
+static REPOSITORIES: LazyLock<Vec<Repository>> = LazyLock::new(|| {
+    load::<Repositories>("repositories.toml", include_str!("../../assets/content/repositories.toml")).repositories
+});
+// Newest first, whatever order the file is in
+static NEWS: LazyLock<Vec<NewsItem>> = LazyLock::new(|| {
+    let mut news = load::<NewsFeed>("news.toml", include_str!("../../assets/content/news.toml")).news;
+    news.sort_by_key(|n| std::cmp::Reverse(n.date));
+    news
+});
+// Most recently started first
+static GRANTS: LazyLock<Vec<Grant>> = LazyLock::new(|| {
+    let mut grants = load::<Grants>("grants.toml", include_str!("../../assets/content/grants.toml")).grants;
+    grants.sort_by_key(|g| std::cmp::Reverse(g.start));
+    grants
+});
+static COURSES: LazyLock<Vec<Course>> =
+    LazyLock::new(|| load::<Courses>("courses.toml", include_str!("../../assets/content/courses.toml")).courses);
+// Newest first
+static TALKS: LazyLock<Vec<Talk>> = LazyLock::new(|| {
+    let mut talks = load::<Talks>("talks.toml", include_str!("../../assets/content/talks.toml")).talks;
+    talks.sort_by_key(|t| std::cmp::Reverse(t.date));
+    talks
+});
+static PAGES: LazyLock<Vec<Page>> =
+    LazyLock::new(|| load::<Pages>("pages.toml", include_str!("../../assets/content/pages.toml")).pages);
+static CAL: LazyLock<CalContent> = LazyLock::new(|| load("cal.toml", include_str!("../../assets/content/cal.toml")));
+
+pub(super) fn home() -> &'static HomeContent {
+    &HOME
+}
+
+pub(super) fn lab() -> &'static LabContent {
+    &LAB
+}
+
+pub(super) fn members() -> &'static [Member] {
+    &MEMBERS
+}
+
+pub(super) fn theme(slug: &str) -> Option<&'static ResearchTheme> {
+    LAB.themes.iter().find(|t| t.slug == slug)
+}
+
+pub(super) fn member(id: &str) -> Option<&'static Member> {
+    MEMBERS.iter().find(|m| m.id == id)
+}
+
+pub(super) fn repositories() -> &'static [Repository] {
+    &REPOSITORIES
+}
+
+pub(super) fn news() -> &'static [NewsItem] {
+    &NEWS
+}
+
+pub(super) fn news_item(id: &str) -> Option<&'static NewsItem> {
+    NEWS.iter().find(|n| n.id == id)
+}
+
+// Every tag used by some news item, sorted
+pub(super) fn news_tags() -> Vec<&'static str> {
+    let mut tags: Vec<&str> = NEWS.iter().flat_map(|n| n.tags.iter().map(String::as_str)).collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+pub(super) fn grants() -> &'static [Grant] {
+    &GRANTS
+}
+
+pub(super) fn grant(id: &str) -> Option<&'static Grant> {
+    GRANTS.iter().find(|g| g.id == id)
+}
+
+pub(super) fn courses() -> &'static [Course] {
+    &COURSES
+}
+
+pub(super) fn talks() -> &'static [Talk] {
+    &TALKS
+}
+
+pub(super) fn cal() -> &'static CalContent {
+    &CAL
+}
+
+pub(super) fn pages() -> &'static [Page] {
+    &PAGES
+}
+
+pub(super) fn page(name: &str) -> Option<&'static Page> {
+    PAGES.iter().find(|p| p.name == name)
+}
@@ -1,4 +0,0 @@
-pub const CSS_CONTENT_CONTAINER: &str = "flex justify-center items-start pt-20 w-4/5 mx-auto min-w-[350px] relative z-10";
-pub const CSS_CONTENT_CARD: &str = "bg-white rounded-lg shadow-xl max-w-[80rem] w-full p-8 mb-15 mx-auto";
-pub const CSS_PAGE_TITLE: &str = "text-4xl font-bold text-gray-900 mb-2";
-pub const CSS_LINK_TEXT: &str = "underline hover:text-red-700 transition-colors";
\ No newline at end of file
@@ -1,101 +1,116 @@
 use dioxus::prelude::*;
-use super::routes::Route;
+use super::routes::{nav_pages, page_route, Route};
+use super::theme::{palette_css, use_theme_provider, ThemeToggle};
 
-const BG_IMG_LIGHT: Asset = asset!("/assets/imgs/bg_light.jpg");
-const CSS_PAGE_SELECTED: &str = "text-white hover:text-gray-200 font-extrabold";
-const CSS_PAGE_NORMAL: &str = "text-white hover:text-gray-200 font-normal";
+// gray-700 reads as gray-200 once the dark palette swaps the grays
+const CSS_PAGE_SELECTED: &str = "text-white hover:text-gray-200 dark:hover:text-gray-700 font-extrabold";
+const CSS_PAGE_NORMAL: &str = "text-white hover:text-gray-200 dark:hover:text-gray-700 font-normal";
+const CSS_MENU_ITEM: &str = "block px-4 py-2 rounded hover:bg-white/10";
+// Out of sight until a keyboard user tabs onto it
+const CSS_SKIP_LINK: &str = "sr-only focus:not-sr-only focus:fixed focus:top-2 focus:left-2 focus:z-[60] focus:px-4 focus:py-2 focus:rounded focus:bg-surface focus:text-gray-900 focus:shadow-lg";
+
+const MENU_ID: &str = "site-menu";
+const MENU_BUTTON_ID: &str = "site-menu-button";
 
 #[component]
 pub fn Header() -> Element {
     let current_route = use_route::<Route>();
+    let section = current_route.section();
+    let theme = use_theme_provider();
+    let mut menu_open = use_signal(|| false);
+
+    // Moves focus into the menu when it opens and back to its button when it closes
+    let mut set_menu = move |open: bool| {
+        menu_open.set(open);
+        let target = if open { format!("#{} a", MENU_ID) } else { format!("#{}", MENU_BUTTON_ID) };
+        document::eval(&format!(
+            r#"requestAnimationFrame(() => document.querySelector("{}")?.focus());"#,
+            target
+        ));
+    };
+
     rsx! {
+        document::Style { {palette_css()} }
         div {
             class: "min-h-screen relative",
-            
+            a { href: "#main", class: CSS_SKIP_LINK, "Skip to content" }
+
             div {
                 class: "fixed inset-0",
                 style: format!(
                     "background-image: url('{}'); background-size: cover; background-position: center; width: 100vw; height: 100vh;",
-                    BG_IMG_LIGHT,
+                    theme().palette().background,
                 ),
             }
 
             header {
-                class: "w-full p-2 flex justify-center fixed top-0 left-0 z-50 bg-gray-600/95",
-                nav {
-                    class: "flex space-x-6 text-lg",
-                    Link { 
-                        to: Route::Home {}, 
-                        class: if matches!(current_route, Route::Home {}) {
-                            CSS_PAGE_SELECTED
-                        } else {
-                            CSS_PAGE_NORMAL
-                        },
-                        "Home" 
+                class: "w-full p-2 fixed top-0 left-0 z-50 bg-gray-600/95 dark:bg-gray-950/95",
+                onkeydown: move |e| {
+                    if e.key() == Key::Escape && menu_open() {
+                        set_menu(false);
                     }
-                    Link { 
-                        to: Route::Director { pagename: "gaim".to_string() },
-                        class: {
-                            let is_active = match &current_route {
-                                Route::Director { pagename } if pagename == "gaim" => true,
-                                _ => false,
-                            };
-                            if is_active {
-                                CSS_PAGE_SELECTED
-                            } else {
-                                CSS_PAGE_NORMAL
+                },
+                nav {
+                    aria_label: "Main",
+                    class: "text-lg",
+
+                    // Wide screens: everything in one row
+                    div {
+                        class: "hidden md:flex justify-center items-center space-x-6",
+                        for page in nav_pages() {
+                            Link {
+                                to: page_route(&page.name),
+                                class: if page.name == section { CSS_PAGE_SELECTED } else { CSS_PAGE_NORMAL },
+                                "{page.label}"
                             }
-                        },
-                        "Lab" 
+                        }
+                        ThemeToggle { class: CSS_PAGE_NORMAL }
                     }
-                    // Link { 
-                    //     to: Route::Director { pagename: "pub".to_string() },
-                    //     class: {
-                    //         let is_active = match &current_route {
-                    //             Route::Director { pagename } if pagename == "pub" => true,
-                    //             _ => false,
-                    //         };
-                    //         if is_active {
-                    //             CSS_PAGE_SELECTED
-                    //         } else {
-                    //             CSS_PAGE_NORMAL
-                    //         }
-                    //     },
-                    //     "Pubs" 
-                    // }
-                    // Link {
-                    //     to: Route::Director { pagename: "code".to_string() },
-                    //     class: {
-                    //         let is_active = match &current_route {
-                    //             Route::Director { pagename } if pagename == "code" => true,
-                    //             _ => false,
-                    //         };
-                    //         if is_active {
-                    //             CSS_PAGE_SELECTED
-                    //         } else {
-                    //             CSS_PAGE_NORMAL
-                    //         }
-                    //     },
-                    //     "Code"
-                    // }
-                    Link {
-                        to: Route::Director { pagename: "res".to_string() },
-                        class: {
-                            let is_active = match &current_route {
-                                Route::Director { pagename } if pagename == "res" => true,
-                                _ => false,
-                            };
-                            if is_active {
-                                CSS_PAGE_SELECTED
-                            } else {
-                                CSS_PAGE_NORMAL
+
+                    // Phones: a menu button, with the links in a list that opens below it
+                    div {
+                        class: "flex md:hidden items-center justify-between px-2",
+                        button {
+                            id: MENU_BUTTON_ID,
+                            r#type: "button",
+                            class: "{CSS_PAGE_NORMAL} cursor-pointer",
+                            aria_controls: MENU_ID,
+                            aria_expanded: if menu_open() { "true" } else { "false" },
+                            aria_label: if menu_open() { "Close menu" } else { "Open menu" },
+                            onclick: move |_| set_menu(!menu_open()),
+                            i { class: if menu_open() { "fa-solid fa-xmark" } else { "fa-solid fa-bars" }, aria_hidden: "true" }
+                        }
+                        ThemeToggle { class: CSS_PAGE_NORMAL }
+                    }
+                    if menu_open() {
+                        ul {
+                            id: MENU_ID,
+                            class: "md:hidden flex flex-col pt-2",
+                            for page in nav_pages() {
+                                li {
+                                    Link {
+                                        to: page_route(&page.name),
+                                        class: if page.name == section {
+                                            format!("{} {}", CSS_MENU_ITEM, CSS_PAGE_SELECTED)
+                                        } else {
+                                            format!("{} {}", CSS_MENU_ITEM, CSS_PAGE_NORMAL)
+                                        },
+                                        onclick: move |_| menu_open.set(false),
+                                        i { class: "{page.icon} w-6 mr-2 text-center", aria_hidden: "true" }
+                                        "{page.label}"
+                                    }
+                                }
                             }
-                        },
-                        "Resources"
+                        }
                     }
                 }
             }
-            Outlet::<Route> {}
+            // Focusable so the skip link moves keyboard focus here too
+            main {
+                id: "main",
+                tabindex: "-1",
+                Outlet::<Route> {}
+            }
         }
     }
 }
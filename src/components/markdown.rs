@@ -0,0 +1,175 @@
+use dioxus::prelude::*;
+use pulldown_cmark::{Event, Parser, Tag};
+use super::theme::STYLES;
+use super::ui::ExternalLink;
+
+// Classes applied to each kind of element, so the same text can match the card it sits in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct ProseStyle {
+    pub(super) paragraph: &'static str,
+    pub(super) strong: &'static str,
+    pub(super) em: &'static str,
+    pub(super) link: &'static str,
+    pub(super) list: &'static str,
+    pub(super) ordered_list: &'static str,
+    pub(super) code: &'static str,
+}
+
+impl ProseStyle {
+    pub(super) const DEFAULT: ProseStyle = ProseStyle {
+        paragraph: "text-gray-600 text-lg leading-relaxed",
+        strong: "font-semibold",
+        em: "",
+        link: STYLES.link,
+        list: "list-disc pl-6 space-y-1",
+        ordered_list: "list-decimal pl-6 space-y-1",
+        code: "font-mono text-sm bg-gray-100 rounded px-1",
+    };
+
+    // Text inside a `LinkCard`. Its links stay clickable above the card's own link.
+    pub(super) const CARD: ProseStyle = ProseStyle {
+        link: "relative z-10 underline hover:text-red-700 transition-colors",
+        ..ProseStyle::DEFAULT
+    };
+}
+
+impl Default for ProseStyle {
+    fn default() -> Self {
+        ProseStyle::DEFAULT
+    }
+}
+
+// Only these schemes become links; anything else (javascript:, data:, ...) is shown as text
+const ALLOWED_SCHEMES: [&str; 3] = ["http://", "https://", "mailto:"];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Code(String),
+    Paragraph(Vec<Node>),
+    Strong(Vec<Node>),
+    Emphasis(Vec<Node>),
+    Link { href: String, children: Vec<Node> },
+    List { ordered: bool, items: Vec<Node> },
+    Item(Vec<Node>),
+    CodeBlock(Vec<Node>),
+    // Formatting we do not support, its text is kept
+    Plain(Vec<Node>),
+}
+
+fn is_safe_url(url: &str) -> bool {
+    let url = url.trim();
+    let lower = url.to_lowercase();
+    (url.starts_with('/') && !url.starts_with("//"))
+        || url.starts_with('#')
+        || ALLOWED_SCHEMES.iter().any(|scheme| lower.starts_with(scheme))
+}
+
+// Children of the innermost open element
+fn children<'s>(stack: &'s mut [(Option<Tag<'_>>, Vec<Node>)]) -> &'s mut Vec<Node> {
+    &mut stack.last_mut().expect("the root is never popped").1
+}
+
+fn parse(text: &str) -> Vec<Node> {
+    // Each open element collects its children until its end tag arrives
+    let mut stack: Vec<(Option<Tag>, Vec<Node>)> = vec![(None, Vec::new())];
+    for event in Parser::new(text.trim()) {
+        match event {
+            Event::Start(tag) => stack.push((Some(tag), Vec::new())),
+            Event::End(_) => {
+                let (tag, nodes) = stack.pop().expect("every end has a start");
+                let node = match tag {
+                    Some(Tag::Paragraph) => Node::Paragraph(nodes),
+                    Some(Tag::Strong) => Node::Strong(nodes),
+                    Some(Tag::Emphasis) => Node::Emphasis(nodes),
+                    Some(Tag::Link { dest_url, .. }) if is_safe_url(&dest_url) => {
+                        Node::Link { href: dest_url.trim().to_string(), children: nodes }
+                    }
+                    Some(Tag::List(start)) => Node::List { ordered: start.is_some(), items: nodes },
+                    Some(Tag::Item) => Node::Item(nodes),
+                    Some(Tag::CodeBlock(_)) => Node::CodeBlock(nodes),
+                    _ => Node::Plain(nodes),
+                };
+                children(&mut stack).push(node);
+            }
+            Event::Text(text) => children(&mut stack).push(Node::Text(text.to_string())),
+            Event::Code(text) => children(&mut stack).push(Node::Code(text.to_string())),
+            Event::SoftBreak | Event::HardBreak => children(&mut stack).push(Node::Text(" ".to_string())),
+            // Raw HTML is dropped, the text between tags still comes through as Text
+            _ => {}
+        }
+    }
+    stack.pop().map(|(_, nodes)| nodes).unwrap_or_default()
+}
+
+fn collect_text(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) | Node::Code(text) => out.push_str(text),
+            Node::Paragraph(children)
+            | Node::Strong(children)
+            | Node::Emphasis(children)
+            | Node::Link { children, .. }
+            | Node::Item(children)
+            | Node::CodeBlock(children)
+            | Node::Plain(children) => collect_text(children, out),
+            Node::List { items, .. } => collect_text(items, out),
+        }
+    }
+}
+
+// The text without any formatting, e.g. for `alt` and `aria-label`
+pub(super) fn plain_text(text: &str) -> String {
+    let mut out = String::new();
+    collect_text(&parse(text), &mut out);
+    out
+}
+
+fn render(nodes: &[Node], style: ProseStyle, inline: bool) -> Element {
+    rsx! {
+        for node in nodes {
+            match node {
+                Node::Text(text) => rsx! { "{text}" },
+                Node::Code(text) => rsx! { code { class: style.code, "{text}" } },
+                Node::Paragraph(children) if inline => render(children, style, inline),
+                Node::Paragraph(children) => rsx! { p { class: style.paragraph, {render(children, style, inline)} } },
+                Node::Strong(children) => rsx! { b { class: style.strong, {render(children, style, inline)} } },
+                Node::Emphasis(children) => rsx! { i { class: style.em, {render(children, style, inline)} } },
+                // Site pages go through the router, so following them does not reload the app
+                Node::Link { href, children } if href.starts_with('/') => rsx! {
+                    Link { to: NavigationTarget::Internal(href.clone()), class: style.link, {render(children, style, inline)} }
+                },
+                Node::Link { href, children } if href.starts_with('#') => rsx! {
+                    a { href: "{href}", class: style.link, {render(children, style, inline)} }
+                },
+                Node::Link { href, children } => rsx! {
+                    ExternalLink { href: "{href}", class: style.link, {render(children, style, inline)} }
+                },
+                Node::List { ordered: true, items } => rsx! {
+                    ol { class: style.ordered_list, {render(items, style, inline)} }
+                },
+                Node::List { ordered: false, items } => rsx! { ul { class: style.list, {render(items, style, inline)} } },
+                Node::Item(children) => rsx! { li { {render(children, style, inline)} } },
+                Node::CodeBlock(children) => rsx! {
+                    pre { class: "{style.code} block p-2 overflow-x-auto", code { {render(children, style, inline)} } }
+                },
+                Node::Plain(children) => render(children, style, inline),
+            }
+        }
+    }
+}
+
+// Renders a CommonMark subset (emphasis, links, lists and code) without ever
+// passing raw HTML to the page
+#[component]
+pub(super) fn Markdown(text: String, #[props(default)] style: ProseStyle) -> Element {
+    let nodes = parse(&text);
+    render(&nodes, style, false)
+}
+
+// Same as `Markdown` but without paragraph tags, for text inside headings and spans
+#[component]
+pub(super) fn InlineMarkdown(text: String, #[props(default)] style: ProseStyle) -> Element {
+    let nodes = parse(&text);
+    render(&nodes, style, true)
+}
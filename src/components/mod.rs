@@ -1,12 +1,32 @@
 mod header;
 mod page_cal;
 mod page_code;
+mod page_cv;
 mod page_gaim;
 mod page_info;
 mod page_main;
+mod page_member;
+mod page_news;
+mod page_not_found;
 mod page_pub;
 mod page_res;
-mod css_preset;
+mod page_talks;
+mod page_teaching;
+mod page_theme;
+mod theme;
+mod ui;
+mod content;
+mod markdown;
+mod preload;
+pub mod timezone;
 
 mod routes;
-pub use routes::Route;
+pub use routes::{site_paths, Build, Route};
+pub use page_cal::{availability, ics, request, rules};
+pub use page_res::sheet;
+pub use preload::Preloaded;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod site;
+#[cfg(not(target_arch = "wasm32"))]
+pub use site::{dataset_links, render_markdown, render_path, render_site, sitemap};
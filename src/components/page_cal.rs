@@ -1,96 +1,480 @@
 use dioxus::prelude::*;
-use super::css_preset::*;
+use chrono::{Duration, TimeZone, Utc};
+use chrono_tz::Tz;
+use wasm_bindgen_futures::spawn_local;
+use super::theme::STYLES;
+use super::ui::{Callout, LinkCard, PageShell};
+use super::content;
+use super::markdown::{Markdown, ProseStyle};
+use super::preload::use_preloaded;
+use super::timezone::{in_zone, offset_label, same_clock, use_visitor_tz, with_lab_time, LAB_TZ};
+
+pub mod availability;
+pub mod ics;
+pub mod request;
+pub mod rules;
+
+use availability::{week, week_start, BlockKind, CalendarSource, HttpSource};
+use ics::{busy_intervals, Interval};
+use request::{Field, MeetingMode, MeetingRequest, RequestSettings, MAX_SLOTS};
+use rules::MeetingRules;
+
+const INSTRUCTION_STYLE: ProseStyle = ProseStyle {
+    strong: "",
+    em: "hover:text-red-700 transition-colors",
+    link: "underline hover:text-red-900 transition-colors",
+    ..ProseStyle::DEFAULT
+};
+
+const CSS_WEEK_BUTTON: &str = "px-3 py-1 rounded border border-gray-300 text-gray-700 hover:border-red-400 hover:text-red-700 transition-colors cursor-pointer";
+const CSS_BLOCK_BUSY: &str = "absolute inset-x-0.5 rounded bg-red-300/80 border border-red-400";
+const CSS_BLOCK_FREE: &str = "absolute inset-x-0.5 rounded bg-gray-50";
+const CSS_SLOT: &str = "absolute inset-x-1 rounded bg-green-200 border border-green-400 text-[10px] leading-tight text-green-900 px-1 overflow-hidden text-left hover:bg-green-300 cursor-pointer";
+const CSS_SLOT_PICKED: &str = "absolute inset-x-1 rounded bg-green-600 border border-green-800 text-[10px] leading-tight text-white px-1 overflow-hidden text-left cursor-pointer";
+const CSS_LABEL: &str = "block text-sm font-semibold text-gray-800 mb-1";
+const CSS_INPUT: &str = "w-full px-3 py-2 rounded border border-gray-300 focus:border-red-400 focus:outline-none";
+const CSS_ERROR: &str = "text-sm text-red-700 mt-1";
+const CSS_FORM_BUTTON: &str = "inline-block px-4 py-2 rounded border border-red-400 text-red-700 hover:bg-red-50 transition-colors cursor-pointer";
+
+// Height of the day columns
+const WEEK_HEIGHT_PX: u32 = 480;
+
+// Feeds named by `feed` in assets/content/cal.toml that are served with the site
+const CALENDAR_DIR: Asset = asset!("/assets/calendar");
+
+// A file in assets/calendar, or any other address as it is written
+fn feed_url(feed: &str) -> String {
+    if feed.contains("://") {
+        feed.to_string()
+    } else {
+        format!("{}/{}", CALENDAR_DIR, feed)
+    }
+}
 
 #[component]
-pub fn Cal() -> Element {
-    
-    use_effect(|| {
-        // Inject Cal.com embed script if not already present
-        let document = gloo::utils::document();
-        let script_id = "cal-embed-script";
-        if document.get_element_by_id(script_id).is_none() {
-            let script = document.create_element("script").unwrap();
-            script.set_attribute("type", "text/javascript").unwrap();
-            script.set_attribute("id", script_id).unwrap();
-            script.set_inner_html(r#"
-                (function (C, A, L) { let p = function (a, ar) { a.q.push(ar); }; let d = C.document; C.Cal = C.Cal || function () { let cal = C.Cal; let ar = arguments; if (!cal.loaded) { cal.ns = {}; cal.q = cal.q || []; d.head.appendChild(d.createElement("script")).src = A; cal.loaded = true; } if (ar[0] === L) { const api = function () { p(api, arguments); }; const namespace = ar[1]; api.q = api.q || []; if(typeof namespace === "string"){cal.ns[namespace] = cal.ns[namespace] || api;p(cal.ns[namespace], ar);p(cal, ["initNamespace", namespace]);} else p(cal, ar); return;} p(cal, ar); }; })(window, "https://app.cal.com/embed/embed.js", "init");
-                Cal("init", "meet", {origin:"https://app.cal.com"});
-                Cal.ns.meet("ui", {"hideEventTypeDetails":false,"layout":"week_view"});
-            "#);
-            document.body().unwrap().append_child(&script).unwrap();
-        }
-        // Set custom data attributes on the button after mount
-        if let Some(button) = document.get_element_by_id("cal-meet-btn") {
-            button.set_attribute("data-cal-link", "huweiming/meet").ok();
-            button.set_attribute("data-cal-namespace", "meet").ok();
-            button.set_attribute("data-cal-config", "{\"layout\":\"week_view\"}").ok();
-        }
+fn WeekView(feed: String, day_start: u32, day_end: u32, rules: MeetingRules, tz: Tz, picked: Signal<Vec<Interval>>) -> Element {
+    // The feed is downloaded once, each week is cut out of it when shown
+    let preloaded = use_preloaded();
+    let mut calendar = use_signal(|| preloaded.calendar.map(Ok::<String, String>));
+    let mut week_offset = use_signal(|| 0i64);
+
+    use_effect(move || {
+        let source = HttpSource::new(feed_url(&feed));
+        spawn_local(async move {
+            calendar.set(Some(source.load().await));
+        });
     });
 
+    // Reading the feed is the slow part, so it only runs again when the week or the zones
+    // change, not each time a slot is picked
+    let calendar_tz = rules.tz();
+    let shown_week = use_memo(use_reactive!(|(tz, calendar_tz)| {
+        let first_day = week_start(Utc::now().with_timezone(&tz).date_naive()) + Duration::weeks(week_offset());
+        // A day of margin on both sides covers any timezone
+        let midnight = Utc.from_utc_datetime(&first_day.and_hms_opt(0, 0, 0).expect("midnight exists"));
+        let range = Interval { start: midnight - Duration::days(1), end: midnight + Duration::days(8) };
+        let busy = match &*calendar.read() {
+            Some(Ok(text)) => busy_intervals(text, range, calendar_tz),
+            _ => Vec::new(),
+        };
+        (first_day, range, busy)
+    }));
+
+    match &*calendar.read() {
+        None => return rsx! { div { class: "text-gray-400 py-8", "Loading availability ..." } },
+        Some(Err(_)) => return rsx! {
+            div { class: "text-gray-600 py-8", "The calendar could not be loaded right now. Please email me to find a time." }
+        },
+        Some(Ok(_)) => {}
+    }
+
+    let (first_day, range, busy) = shown_week();
+    let days = week(&busy, first_day, &tz, (day_start, day_end));
+    // Nothing in the past can be booked
+    let bookable = Interval { start: range.start.max(Utc::now()), end: range.end };
+    let slots = rules.slots(&busy, bookable);
+    let hours: Vec<u32> = (day_start..day_end).collect();
+    let now = Utc::now();
+    let offset = offset_label(now, tz);
+    let lab_now = (!same_clock(now, tz, LAB_TZ)).then(|| in_zone(now, LAB_TZ, "%H:%M"));
+
     rsx! {
         div {
-            class: format!("{} relative z-10", CSS_CONTENT_CONTAINER),
+            class: "space-y-3",
             div {
-                class: CSS_CONTENT_CARD,
+                class: "flex flex-wrap items-center justify-between gap-2",
+                button {
+                    r#type: "button",
+                    class: CSS_WEEK_BUTTON,
+                    onclick: move |_| week_offset -= 1,
+                    "← Previous week"
+                }
+                span {
+                    class: "font-semibold text-gray-800",
+                    "Week of {first_day.format(\"%B %-d, %Y\")}"
+                }
+                button {
+                    r#type: "button",
+                    class: CSS_WEEK_BUTTON,
+                    onclick: move |_| week_offset += 1,
+                    "Next week →"
+                }
+            }
+            p {
+                class: "text-sm text-gray-500",
+                "Times are shown in your timezone, {tz.name()} ({offset}). "
+                if let Some(lab_now) = lab_now {
+                    "It is {lab_now} in the lab. "
+                }
+                "Click up to {MAX_SLOTS} open slots to add them to your request."
+            }
+
+            div {
+                class: "overflow-x-auto",
                 div {
-                    class: "mb-12 space-y-6",
-                    h2 {
-                        class: CSS_PAGE_TITLE,
-                        "Busy / Available"
-                    }
-                    p {
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        "I share my calendar here with the hope that technologies can make"
-                        " our lives easier. Curious how this is done? Check out "
-                        a {
-                            href: "https://cal.com/",
-                            target: "_blank",
-                            class: "underline hover:text-red-900 transition-colors",
-                            "Cal.com"
+                    class: "grid grid-cols-[3rem_repeat(7,minmax(5rem,1fr))] gap-1 min-w-[640px]",
+
+                    div {}
+                    for day in days.iter() {
+                        div {
+                            class: "text-center text-sm font-semibold text-gray-700",
+                            "{day.date.format(\"%a %-d\")}"
                         }
-                        "."
                     }
-                    p {
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        b {
-                            "You do not have to book from here."
+
+                    div {
+                        class: "relative",
+                        style: "height: {WEEK_HEIGHT_PX}px;",
+                        for (i, hour) in hours.iter().enumerate() {
+                            span {
+                                class: "absolute right-1 text-xs text-gray-400",
+                                style: "top: {i as f64 * 100.0 / hours.len() as f64}%;",
+                                "{hour}:00"
+                            }
                         }
-                        " Simply find an available time period and send your calendar invite to "
-                        i {
-                            class: "hover:text-red-700 transition-colors",
-                            "weiming@uga.edu"
+                    }
+                    for day in days.iter() {
+                        div {
+                            class: "relative border-l border-gray-200",
+                            style: "height: {WEEK_HEIGHT_PX}px;",
+                            for block in day.blocks.iter() {
+                                div {
+                                    class: if block.kind == BlockKind::Busy { CSS_BLOCK_BUSY } else { CSS_BLOCK_FREE },
+                                    style: format!(
+                                        "top: {:.3}%; height: {:.3}%;",
+                                        day.position(block.interval.start) * 100.0,
+                                        (day.position(block.interval.end) - day.position(block.interval.start)) * 100.0,
+                                    ),
+                                    title: format!(
+                                        "{} {}–{}",
+                                        if block.kind == BlockKind::Busy { "Busy" } else { "Free" },
+                                        block.interval.start.with_timezone(&tz).format("%H:%M"),
+                                        block.interval.end.with_timezone(&tz).format("%H:%M"),
+                                    ),
+                                }
+                            }
+                            for slot in slots.iter().filter(|s| s.interval.overlaps(&day.window)) {
+                                button {
+                                    r#type: "button",
+                                    class: if picked.read().contains(&slot.interval) { CSS_SLOT_PICKED } else { CSS_SLOT },
+                                    aria_pressed: if picked.read().contains(&slot.interval) { "true" } else { "false" },
+                                    onclick: {
+                                        let interval = slot.interval;
+                                        move |_| {
+                                            let mut picked = picked.write();
+                                            if let Some(i) = picked.iter().position(|p| *p == interval) {
+                                                picked.remove(i);
+                                            } else if picked.len() < MAX_SLOTS {
+                                                picked.push(interval);
+                                            }
+                                        }
+                                    },
+                                    style: format!(
+                                        "top: {:.3}%; height: {:.3}%;",
+                                        day.position(slot.interval.start) * 100.0,
+                                        (day.position(slot.interval.end) - day.position(slot.interval.start)) * 100.0,
+                                    ),
+                                    title: format!(
+                                        "{}: {}, {}",
+                                        rules.slot_type(&slot.slot_type).map_or("Open", |t| t.label.as_str()),
+                                        with_lab_time(slot.interval.start, tz, "%a %H:%M"),
+                                        rules.slot_type(&slot.slot_type).map_or("", |t| t.location.as_str()),
+                                    ),
+                                    {slot.interval.start.with_timezone(&tz).format("%H:%M").to_string()}
+                                }
+                            }
                         }
-                        " with topics/agenda and your preferred meeting options, e.g., "
-                        "room # for in-person meetings (if my office, GGY 312) or Google/Zoom/Team links. "
-                        "If you book from here, only 30-minute meetings are allowed."
                     }
-                    p{
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        b { 
-                            "If it is urgent and no available time can be found",
-                         }
-                        ", please reach "
-                        "out to me directly so we can figure out something else."
+                }
+            }
+
+            div {
+                class: "flex gap-4 text-sm text-gray-600",
+                span { span { class: "inline-block w-3 h-3 mr-1 rounded bg-red-300" } "Busy" }
+                span { span { class: "inline-block w-3 h-3 mr-1 rounded bg-green-200 border border-green-400" } "Open for meetings" }
+                span { span { class: "inline-block w-3 h-3 mr-1 rounded bg-green-600" } "Picked" }
+                span { span { class: "inline-block w-3 h-3 mr-1 rounded bg-gray-50 border border-gray-200" } "Free, outside meeting hours" }
+            }
+        }
+    }
+}
+
+// Hands the invite to the browser as a file download
+fn download_ics(text: &str) {
+    let text = serde_json::to_string(text).unwrap_or_default();
+    document::eval(&format!(
+        r#"
+        const url = URL.createObjectURL(new Blob([{text}], {{ type: "text/calendar" }}));
+        const link = document.createElement("a");
+        link.href = url;
+        link.download = "meeting_request.ics";
+        link.click();
+        URL.revokeObjectURL(url);
+        "#
+    ));
+}
+
+#[component]
+fn FieldError(problems: Vec<request::Problem>, field: Field) -> Element {
+    rsx! {
+        for problem in problems.iter().filter(|p| p.field == field) {
+            p { class: CSS_ERROR, "{problem.message}" }
+        }
+    }
+}
+
+#[component]
+fn MeetingForm(settings: RequestSettings, tz: Tz, visitor: Tz, picked: Signal<Vec<Interval>>) -> Element {
+    let mut name = use_signal(String::new);
+    let mut email = use_signal(String::new);
+    let mut topic = use_signal(String::new);
+    let mut agenda = use_signal(String::new);
+    let mut other_times = use_signal(String::new);
+    let mut mode = use_signal(|| MeetingMode::InPerson);
+    // Errors are only shown once the visitor tries to send
+    let mut attempted = use_signal(|| false);
+
+    let request = MeetingRequest {
+        name: name(),
+        email: email(),
+        topic: topic(),
+        agenda: agenda(),
+        mode: mode(),
+        slots: picked(),
+        other_times: other_times(),
+    };
+    let problems = request.problems();
+    let shown = if attempted() { problems.clone() } else { Vec::new() };
+    let mailto = request.mailto(&settings, tz);
+    let invite = request.to_ics(&settings, tz, Utc::now());
+
+    rsx! {
+        form {
+            class: "space-y-4 p-4 border border-gray-200 rounded-lg bg-surface",
+            novalidate: true,
+            onsubmit: move |e| e.prevent_default(),
+            h3 { class: "text-lg font-semibold text-gray-900", "Request a meeting" }
+
+            div {
+                class: "grid gap-4 md:grid-cols-2",
+                div {
+                    label { class: CSS_LABEL, r#for: "meet-name", "Name" }
+                    input {
+                        id: "meet-name",
+                        class: CSS_INPUT,
+                        autocomplete: "name",
+                        value: "{name}",
+                        oninput: move |e| name.set(e.value()),
+                    }
+                    FieldError { problems: shown.clone(), field: Field::Name }
+                }
+                div {
+                    label { class: CSS_LABEL, r#for: "meet-email", "Email" }
+                    input {
+                        id: "meet-email",
+                        r#type: "email",
+                        class: CSS_INPUT,
+                        autocomplete: "email",
+                        value: "{email}",
+                        oninput: move |e| email.set(e.value()),
                     }
-                    p {
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        b {
-                            "The calendar updates itself after any scheduling changes."
+                    FieldError { problems: shown.clone(), field: Field::Email }
+                }
+            }
+
+            div {
+                label { class: CSS_LABEL, r#for: "meet-topic", "Topic" }
+                input {
+                    id: "meet-topic",
+                    class: CSS_INPUT,
+                    value: "{topic}",
+                    oninput: move |e| topic.set(e.value()),
+                }
+                FieldError { problems: shown.clone(), field: Field::Topic }
+            }
+
+            div {
+                label { class: CSS_LABEL, r#for: "meet-agenda", "Agenda (optional)" }
+                textarea {
+                    id: "meet-agenda",
+                    class: CSS_INPUT,
+                    rows: 4,
+                    value: "{agenda}",
+                    oninput: move |e| agenda.set(e.value()),
+                }
+                FieldError { problems: shown.clone(), field: Field::Agenda }
+            }
+
+            fieldset {
+                legend { class: CSS_LABEL, "Meeting" }
+                div {
+                    class: "flex flex-wrap gap-6 text-gray-700",
+                    for option in [MeetingMode::InPerson, MeetingMode::Video] {
+                        label {
+                            class: "flex items-center gap-2 cursor-pointer",
+                            input {
+                                r#type: "radio",
+                                name: "meet-mode",
+                                checked: mode() == option,
+                                onchange: move |_| mode.set(option),
+                            }
+                            span {
+                                "{option.label()} "
+                                span {
+                                    class: "text-sm text-gray-500",
+                                    if option == MeetingMode::InPerson { "({settings.in_person})" } else { "({settings.video})" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                p { class: CSS_LABEL, "Preferred times" }
+                if picked.read().is_empty() {
+                    p { class: "text-sm text-gray-500", "Pick open slots in the week above, or describe when you are free below." }
+                } else {
+                    ol {
+                        class: "list-decimal pl-6 space-y-1 text-gray-700",
+                        for (i, slot) in picked().into_iter().enumerate() {
+                            li {
+                                key: "{slot.start}",
+                                {format!("{} ", with_lab_time(slot.start, visitor, "%a, %b %-d %H:%M"))}
+                                if i == 0 { span { class: "text-sm text-gray-500", "(first choice) " } }
+                                button {
+                                    r#type: "button",
+                                    class: "text-sm underline text-red-700 hover:text-red-900 cursor-pointer",
+                                    onclick: move |_| {
+                                        picked.write().retain(|p| *p != slot);
+                                    },
+                                    "Remove"
+                                }
+                            }
                         }
-                        " So you might see your booked slots turn busy right after you send me the invite. "
-                        "I will decline any meetings if I cannot make it but I will make sure to communicate this with you."
                     }
-                    // Cal.com element-click embed button
+                }
+                label { class: "block text-sm text-gray-700 mt-2", r#for: "meet-other", "Other times that work for you (optional)" }
+                input {
+                    id: "meet-other",
+                    class: CSS_INPUT,
+                    value: "{other_times}",
+                    oninput: move |e| other_times.set(e.value()),
+                }
+                FieldError { problems: shown.clone(), field: Field::Times }
+            }
+
+            div {
+                class: "flex flex-wrap items-center gap-3",
+                if problems.is_empty() {
+                    a { class: CSS_FORM_BUTTON, href: "{mailto}", i { class: "fa-solid fa-envelope mr-2" } "Email the request" }
+                } else {
                     button {
-                        id: "cal-meet-btn",
-                        class: "block mb-4 p-4 border-l-4 border-red-400 rounded-r-lg bg-white hover:bg-gray-50 hover:shadow-lg hover:border-red-500 transition-all duration-200 cursor-pointer transform hover:-translate-y-1",
+                        r#type: "button",
+                        class: CSS_FORM_BUTTON,
+                        onclick: move |_| attempted.set(true),
+                        i { class: "fa-solid fa-envelope mr-2" }
+                        "Email the request"
+                    }
+                }
+                // An invite needs a picked time
+                if invite.is_some() {
+                    button {
+                        r#type: "button",
+                        class: CSS_FORM_BUTTON,
+                        onclick: move |_| {
+                            attempted.set(true);
+                            if let (true, Some(invite)) = (problems.is_empty(), &invite) {
+                                download_ics(invite);
+                            }
+                        },
+                        i { class: "fa-solid fa-calendar-plus mr-2" }
+                        "Download invite (.ics)"
+                    }
+                }
+            }
+            p {
+                class: "text-sm text-gray-500",
+                "The invite holds your first choice and lists the others; send it to {settings.to} from your own calendar. Nothing is sent from this page."
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Cal() -> Element {
+    let content = content::cal();
+    let settings = &content.availability;
+    let today = Utc::now().with_timezone(&content.rules.tz()).date_naive();
+    let picked = use_signal(Vec::<Interval>::new);
+    let visitor = use_visitor_tz();
+
+    rsx! {
+        PageShell {
+            div {
+                class: "mb-12 space-y-6",
+                h1 {
+                    class: STYLES.page_title,
+                    "{content.title}"
+                }
+                for paragraph in content.instructions.iter() {
+                    Markdown { text: paragraph.clone(), style: INSTRUCTION_STYLE }
+                }
 
-                        p { 
-                            class: "text-lg font-semibold text-gray-900 hover:text-red-700 transition-colors",
-                            "See My Busy/Available Times"
-                         }
+                Callout {
+                    title: "How meetings work",
+                    ul {
+                        class: "list-disc pl-6 space-y-1 text-gray-700",
+                        for line in content.rules.describe(today) {
+                            li { "{line}" }
+                        }
+                    }
+                }
+
+                if !settings.feed.is_empty() {
+                    WeekView {
+                        feed: settings.feed.clone(),
+                        day_start: settings.day_start,
+                        day_end: settings.day_end,
+                        rules: content.rules.clone(),
+                        tz: visitor(),
+                        picked,
+                    }
+                }
+
+                MeetingForm { settings: content.request.clone(), tz: content.rules.tz(), visitor: visitor(), picked }
+
+                if let Some(booking) = &content.booking {
+                    LinkCard {
+                        to: booking.url.clone(),
+                        label: booking.label.clone(),
+                        new_tab: true,
+                        class: "mb-4",
+                        p {
+                            class: "text-lg font-semibold text-gray-900 group-hover:text-red-700 transition-colors",
+                            "{booking.label}"
+                        }
                     }
-                    
                 }
             }
         }
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use std::future::Future;
+use super::ics::{busy_intervals, Interval};
+use super::super::timezone::resolve;
+
+// Anything that can hand over the text of an iCalendar feed. The page reads a URL,
+// tests read a file.
+pub trait CalendarSource {
+    fn load(&self) -> impl Future<Output = Result<String, String>>;
+}
+
+pub struct HttpSource {
+    url: String,
+}
+
+impl HttpSource {
+    pub fn new(url: impl Into<String>) -> Self {
+        HttpSource { url: url.into() }
+    }
+}
+
+impl CalendarSource for HttpSource {
+    async fn load(&self) -> Result<String, String> {
+        let resp = reqwest::get(&self.url).await.map_err(|e| e.to_string())?;
+        if !resp.status().is_success() {
+            return Err(format!("{} returned {}", self.url, resp.status()));
+        }
+        resp.text().await.map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileSource {
+    path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileSource {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        FileSource { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CalendarSource for FileSource {
+    async fn load(&self) -> Result<String, String> {
+        std::fs::read_to_string(&self.path).map_err(|e| format!("{}: {}", self.path.display(), e))
+    }
+}
+
+pub async fn load_busy(source: &impl CalendarSource, range: Interval, tz: chrono_tz::Tz) -> Result<Vec<Interval>, String> {
+    Ok(busy_intervals(&source.load().await?, range, tz))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockKind {
+    Free,
+    Busy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub interval: Interval,
+    pub kind: BlockKind,
+}
+
+// The shown hours of one day, split into free and busy blocks that cover it without gaps
+#[derive(Debug, Clone, PartialEq)]
+pub struct Day {
+    pub date: NaiveDate,
+    pub window: Interval,
+    pub blocks: Vec<Block>,
+}
+
+impl Day {
+    // Where `time` falls in the window, from 0 to 1, for placing blocks on the page
+    pub fn position(&self, time: DateTime<Utc>) -> f64 {
+        let total = (self.window.end - self.window.start).num_seconds().max(1) as f64;
+        ((time - self.window.start).num_seconds() as f64 / total).clamp(0.0, 1.0)
+    }
+}
+
+// Monday of the week `date` falls in
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+// `hour` o'clock on `date` in `tz`
+fn local_time<Tz: TimeZone>(tz: &Tz, date: NaiveDate, hour: u32) -> DateTime<Utc> {
+    resolve(tz, date.and_hms_opt(0, 0, 0).expect("midnight exists") + Duration::hours(hour as i64))
+}
+
+// Seven days from `first_day`, each showing `hours` (start and end hour) in the viewer's `tz`
+pub fn week<Tz: TimeZone>(busy: &[Interval], first_day: NaiveDate, tz: &Tz, hours: (u32, u32)) -> Vec<Day> {
+    (0..7)
+        .map(|offset| {
+            let date = first_day + Duration::days(offset);
+            let window = Interval { start: local_time(tz, date, hours.0), end: local_time(tz, date, hours.1) };
+            let mut blocks = Vec::new();
+            let mut cursor = window.start;
+            for interval in busy.iter().filter(|i| i.overlaps(&window)) {
+                let start = interval.start.max(window.start);
+                let end = interval.end.min(window.end);
+                if start > cursor {
+                    blocks.push(Block { interval: Interval { start: cursor, end: start }, kind: BlockKind::Free });
+                }
+                blocks.push(Block { interval: Interval { start, end }, kind: BlockKind::Busy });
+                cursor = cursor.max(end);
+            }
+            if cursor < window.end {
+                blocks.push(Block { interval: Interval { start: cursor, end: window.end }, kind: BlockKind::Free });
+            }
+            Day { date, window, blocks }
+        })
+        .collect()
+}
@@ -0,0 +1,535 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Utc, Weekday};
+use chrono_tz::Tz;
+use std::collections::HashSet;
+use super::super::timezone::resolve;
+
+// Reads the busy times out of an iCalendar (RFC 5545) feed. Only what a free/busy view
+// needs is understood: when each event or free/busy period starts and ends, including
+// daily and weekly repeats and RDATE dates and periods. Exports from Google Calendar and
+// Outlook are the main targets.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl Interval {
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+// Outlook writes Windows zone names instead of IANA ones. The zones people are most likely to use.
+const WINDOWS_ZONES: [(&str, &str); 24] = [
+    ("Eastern Standard Time", "America/New_York"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("Canada Central Standard Time", "America/Regina"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("E. Europe Standard Time", "Europe/Chisinau"),
+    ("FLE Standard Time", "Europe/Kyiv"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("UTC", "Etc/UTC"),
+];
+
+// IANA names, Windows names, and IANA names behind a vendor prefix such as
+// `/mozilla.org/20050126_1/America/New_York`
+fn parse_tzid(name: &str) -> Option<Tz> {
+    let name = name.trim();
+    let suffixes = std::iter::once(name).chain(name.match_indices('/').map(|(i, _)| &name[i + 1..]));
+    for candidate in suffixes {
+        if let Ok(tz) = candidate.parse::<Tz>() {
+            return Some(tz);
+        }
+    }
+    WINDOWS_ZONES
+        .iter()
+        .find(|(windows, _)| windows.eq_ignore_ascii_case(name))
+        .and_then(|(_, iana)| iana.parse().ok())
+}
+
+// One content line, e.g. `DTSTART;TZID=America/New_York:20250101T090000`
+#[derive(Debug, Clone, PartialEq)]
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+}
+
+// Long lines are folded by starting the continuation with a space or tab
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if line.trim().is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+// Splits on `separator` except inside double quotes, where parameter values may contain `:` and `;`
+fn split_unquoted(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quoted = false;
+    let mut from = 0;
+    for (i, c) in text.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if c == separator && !quoted {
+            parts.push(&text[from..i]);
+            from = i + 1;
+        }
+    }
+    parts.push(&text[from..]);
+    parts
+}
+
+fn parse_property(line: &str) -> Option<Property> {
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(i, c)| {
+        if c == '"' {
+            quoted = !quoted;
+        }
+        (c == ':' && !quoted).then_some(i)
+    })?;
+    let mut head = split_unquoted(&line[..colon], ';').into_iter();
+    let name = head.next()?.trim().to_uppercase();
+    let params = head
+        .filter_map(|p| p.split_once('='))
+        .map(|(n, v)| (n.trim().to_uppercase(), v.trim().trim_matches('"').to_string()))
+        .collect();
+    Some(Property { name, params, value: line[colon + 1..].trim().to_string() })
+}
+
+// A moment as written in the feed: wall-clock time in a zone, so repeats keep their local
+// time across DST changes
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Stamp {
+    local: NaiveDateTime,
+    tz: Tz,
+    all_day: bool,
+}
+
+impl Stamp {
+    // Times skipped by a DST change move forward an hour, repeated times take the first
+    fn utc(&self) -> DateTime<Utc> {
+        resolve(&self.tz, self.local)
+    }
+
+    fn at(&self, date: NaiveDate) -> Stamp {
+        Stamp { local: date.and_time(self.local.time()), ..*self }
+    }
+}
+
+// `20250101T150000Z`, `20250101T090000` in `tz`, or the all-day `20250101`
+fn parse_value(value: &str, tz: Tz) -> Option<Stamp> {
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix(['Z', 'z']) {
+        let local = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Stamp { local, tz: Tz::UTC, all_day: false });
+    }
+    if let Ok(local) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S") {
+        return Some(Stamp { local, tz, all_day: false });
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+    Some(Stamp { local: date.and_hms_opt(0, 0, 0)?, tz, all_day: true })
+}
+
+// The zone of a property's TZID, or `default` for floating times and zones we do not know
+fn zone_of(prop: &Property, default: Tz) -> Tz {
+    prop.param("TZID").and_then(parse_tzid).unwrap_or(default)
+}
+
+// All values of a property that may hold a comma separated list, like EXDATE
+fn parse_values(prop: &Property, default: Tz) -> Vec<Stamp> {
+    let tz = zone_of(prop, default);
+    prop.value.split(',').filter_map(|v| parse_value(v, tz)).collect()
+}
+
+// `P1W`, `P1D`, `PT1H30M`, ... as used by DURATION and free/busy periods
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let value = value.strip_prefix('+').unwrap_or(value);
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+    let mut rest = value.strip_prefix('P')?;
+    let mut total = Duration::zero();
+    let mut in_time = false;
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('T') {
+            in_time = true;
+            rest = r;
+            continue;
+        }
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let amount: i64 = rest[..digits].parse().ok()?;
+        let unit = rest[digits..].chars().next()?;
+        total += match (unit, in_time) {
+            ('W', false) => Duration::weeks(amount),
+            ('D', false) => Duration::days(amount),
+            ('H', true) => Duration::hours(amount),
+            ('M', true) => Duration::minutes(amount),
+            ('S', true) => Duration::seconds(amount),
+            _ => return None,
+        };
+        rest = &rest[digits + 1..];
+    }
+    Some(if negative { -total } else { total })
+}
+
+// `start/end` or `start/duration`, as the start and the exact time it lasts
+fn parse_period(value: &str, tz: Tz) -> Option<(Stamp, Duration)> {
+    let (start, end) = value.split_once('/')?;
+    let start = parse_value(start, tz)?;
+    let length = match parse_duration(end) {
+        Some(duration) => duration,
+        None => parse_value(end, tz)?.utc() - start.utc(),
+    };
+    Some((start, length))
+}
+
+// RDATE values are dates or times that last as long as the event, or periods with their own length
+fn parse_rdates(prop: &Property, default: Tz) -> Vec<(Stamp, Option<Duration>)> {
+    let tz = zone_of(prop, default);
+    prop.value
+        .split(',')
+        .filter_map(|v| match parse_period(v, tz) {
+            Some((start, length)) => Some((start, Some(length))),
+            None => parse_value(v, tz).map(|start| (start, None)),
+        })
+        .collect()
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    // Ordinals such as the 1 in `1MO` only matter for monthly rules
+    match code.trim().trim_start_matches(|c: char| c == '+' || c == '-' || c.is_ascii_digit()) {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    frequency: Frequency,
+    interval: i64,
+    count: Option<usize>,
+    until: Option<DateTime<Utc>>,
+    by_day: Vec<Weekday>,
+    week_start: Weekday,
+}
+
+// Upper bound on the days or weeks a rule is followed, against rules without an end
+const MAX_PERIODS: i64 = 20_000;
+
+impl Rule {
+    // Only daily and weekly rules are expanded, other events count once
+    fn parse(value: &str, tz: Tz) -> Option<Rule> {
+        let mut rule = Rule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+        let mut frequency = None;
+        for part in value.split(';') {
+            let Some((key, value)) = part.split_once('=') else { continue };
+            match key.trim().to_uppercase().as_str() {
+                "FREQ" => frequency = Some(value.trim().to_uppercase()),
+                "INTERVAL" => rule.interval = value.trim().parse::<i64>().ok()?.max(1),
+                "COUNT" => rule.count = value.trim().parse().ok(),
+                "UNTIL" => rule.until = parse_value(value, tz).map(|s| s.utc()),
+                "BYDAY" => rule.by_day = value.split(',').filter_map(parse_weekday).collect(),
+                "WKST" => rule.week_start = parse_weekday(value).unwrap_or(Weekday::Mon),
+                _ => {}
+            }
+        }
+        rule.frequency = match frequency?.as_str() {
+            "DAILY" => Frequency::Daily,
+            "WEEKLY" => Frequency::Weekly,
+            _ => return None,
+        };
+        Some(rule)
+    }
+
+    // Dates the rule produces, in order, starting from the first event's date
+    fn dates(&self, first: NaiveDate) -> Box<dyn Iterator<Item = NaiveDate> + '_> {
+        match self.frequency {
+            Frequency::Daily => Box::new(
+                (0..MAX_PERIODS)
+                    .map(move |i| first + Duration::days(i * self.interval))
+                    .filter(|d| self.by_day.is_empty() || self.by_day.contains(&d.weekday())),
+            ),
+            Frequency::Weekly => {
+                let offset = |day: Weekday| (7 + day.num_days_from_monday() - self.week_start.num_days_from_monday()) % 7;
+                let week = first - Duration::days(offset(first.weekday()) as i64);
+                let mut days: Vec<u32> = if self.by_day.is_empty() {
+                    vec![offset(first.weekday())]
+                } else {
+                    self.by_day.iter().map(|d| offset(*d)).collect()
+                };
+                days.sort();
+                days.dedup();
+                Box::new(
+                    (0..MAX_PERIODS)
+                        .flat_map(move |i| {
+                            let week = week + Duration::weeks(i * self.interval);
+                            days.clone().into_iter().map(move |d| week + Duration::days(d as i64))
+                        })
+                        .filter(move |d| *d >= first),
+                )
+            }
+        }
+    }
+}
+
+// How long each occurrence of an event lasts
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Length {
+    // On the wall clock of the start's zone, so an all-day event stays a whole day across DST
+    Local(Duration),
+    Exact(Duration),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Event {
+    uid: Option<String>,
+    start: Stamp,
+    length: Length,
+    rule: Option<Rule>,
+    // RDATE starts, with the length of those given as periods
+    extra: Vec<(Stamp, Option<Duration>)>,
+    excluded: Vec<Stamp>,
+    // Set on an edited copy of one occurrence of a repeating event
+    replaces: Option<Stamp>,
+    // Transparent, cancelled, or marked free by Outlook
+    free: bool,
+}
+
+impl Event {
+    fn from_properties(props: &[Property], default: Tz) -> Option<Event> {
+        let find = |name: &str| props.iter().find(|p| p.name == name);
+        let dtstart = find("DTSTART")?;
+        let start = parse_value(&dtstart.value, zone_of(dtstart, default))?;
+        let tz = start.tz;
+
+        let end = find("DTEND").and_then(|p| parse_value(&p.value, zone_of(p, default)));
+        let length = match (end, find("DURATION").and_then(|p| parse_duration(&p.value))) {
+            (Some(end), _) if end.tz == start.tz => Length::Local(end.local - start.local),
+            (Some(end), _) => Length::Exact(end.utc() - start.utc()),
+            (None, Some(duration)) if start.all_day => Length::Local(duration),
+            (None, Some(duration)) => Length::Exact(duration),
+            // An all-day event without an end lasts the day, any other one is a moment
+            (None, None) if start.all_day => Length::Local(Duration::days(1)),
+            (None, None) => Length::Exact(Duration::zero()),
+        };
+
+        let is = |name: &str, value: &str| find(name).is_some_and(|p| p.value.eq_ignore_ascii_case(value));
+        let free = is("TRANSP", "TRANSPARENT") || is("STATUS", "CANCELLED") || is("X-MICROSOFT-CDO-BUSYSTATUS", "FREE");
+
+        let all = |name: &str| -> Vec<Stamp> {
+            props.iter().filter(|p| p.name == name).flat_map(|p| parse_values(p, tz)).collect()
+        };
+
+        Some(Event {
+            uid: find("UID").map(|p| p.value.clone()),
+            start,
+            length,
+            rule: find("RRULE").and_then(|p| Rule::parse(&p.value, tz)),
+            extra: props.iter().filter(|p| p.name == "RDATE").flat_map(|p| parse_rdates(p, tz)).collect(),
+            excluded: all("EXDATE"),
+            replaces: find("RECURRENCE-ID").and_then(|p| parse_value(&p.value, zone_of(p, tz))),
+            free,
+        })
+    }
+
+    fn is_excluded(&self, start: &Stamp, replaced: &HashSet<(String, DateTime<Utc>)>) -> bool {
+        let edited = self.uid.as_ref().is_some_and(|uid| replaced.contains(&(uid.clone(), start.utc())));
+        // All-day exclusions drop the whole day, even when the event has a time
+        edited
+            || self.excluded.iter().any(|ex| {
+                if ex.all_day { ex.local.date() == start.local.date() } else { ex.utc() == start.utc() }
+            })
+    }
+
+    fn end_of(&self, start: &Stamp) -> DateTime<Utc> {
+        match self.length {
+            Length::Local(length) => resolve(&start.tz, start.local + length),
+            Length::Exact(length) => start.utc() + length,
+        }
+    }
+
+    // Every occurrence that overlaps `range`
+    fn occurrences(&self, range: Interval, replaced: &HashSet<(String, DateTime<Utc>)>) -> Vec<Interval> {
+        let mut starts = vec![(self.start, None)];
+        if let Some(rule) = &self.rule {
+            // DTSTART is the first occurrence and counts towards COUNT, even on a day the rule skips
+            let first = self.start.local.date();
+            for date in rule.dates(first).filter(|d| *d != first) {
+                let start = self.start.at(date);
+                let utc = start.utc();
+                if rule.count.is_some_and(|c| starts.len() >= c) || rule.until.is_some_and(|u| utc > u) || utc >= range.end {
+                    break;
+                }
+                starts.push((start, None));
+            }
+        }
+        starts.extend(self.extra.iter().copied());
+
+        let mut seen = HashSet::new();
+        starts
+            .into_iter()
+            .filter(|(s, _)| seen.insert(s.utc()))
+            .filter(|(s, _)| !self.is_excluded(s, replaced))
+            .map(|(s, length)| Interval {
+                start: s.utc(),
+                end: length.map_or_else(|| self.end_of(&s), |length| s.utc() + length),
+            })
+            .filter(|i| i.end > i.start && i.overlaps(&range))
+            .collect()
+    }
+}
+
+// The parts of a feed we read, with their properties
+#[derive(Debug, Default)]
+struct Feed {
+    calendar: Vec<Property>,
+    events: Vec<Vec<Property>>,
+    free_busy: Vec<Vec<Property>>,
+}
+
+fn parse_feed(text: &str) -> Feed {
+    let mut feed = Feed::default();
+    // Components nest (alarms inside events, time zone rules inside time zones), only
+    // properties directly inside the component we are reading count
+    let mut stack: Vec<String> = Vec::new();
+    for line in unfold(text) {
+        let Some(prop) = parse_property(&line) else { continue };
+        match prop.name.as_str() {
+            "BEGIN" => {
+                let name = prop.value.to_uppercase();
+                match name.as_str() {
+                    "VEVENT" => feed.events.push(Vec::new()),
+                    "VFREEBUSY" => feed.free_busy.push(Vec::new()),
+                    _ => {}
+                }
+                stack.push(name);
+            }
+            "END" => {
+                stack.pop();
+            }
+            _ => {
+                let component = match stack.last().map(String::as_str) {
+                    Some("VCALENDAR") => Some(&mut feed.calendar),
+                    Some("VEVENT") => feed.events.last_mut(),
+                    Some("VFREEBUSY") => feed.free_busy.last_mut(),
+                    _ => None,
+                };
+                if let Some(component) = component {
+                    component.push(prop);
+                }
+            }
+        }
+    }
+    feed
+}
+
+// Busy intervals overlapping `range`, clipped to it, sorted and with overlaps merged.
+// Times without a zone, all-day dates included, are read in the calendar's X-WR-TIMEZONE,
+// or in `tz`, the zone the calendar's owner keeps it in.
+pub fn busy_intervals(text: &str, range: Interval, tz: Tz) -> Vec<Interval> {
+    let feed = parse_feed(text);
+    let default = feed
+        .calendar
+        .iter()
+        .find(|p| p.name == "X-WR-TIMEZONE")
+        .and_then(|p| parse_tzid(&p.value))
+        .unwrap_or(tz);
+
+    let events: Vec<Event> = feed.events.iter().filter_map(|props| Event::from_properties(props, default)).collect();
+    // Occurrences that were edited or cancelled one by one are left out of their series
+    let replaced: HashSet<(String, DateTime<Utc>)> = events
+        .iter()
+        .filter_map(|e| Some((e.uid.clone()?, e.replaces?.utc())))
+        .collect();
+
+    let mut busy: Vec<Interval> = events
+        .iter()
+        .filter(|e| !e.free)
+        .flat_map(|e| {
+            let replaced = if e.replaces.is_some() { &HashSet::new() } else { &replaced };
+            e.occurrences(range, replaced)
+        })
+        .collect();
+
+    // Free/busy components list their busy periods directly
+    for props in feed.free_busy.iter() {
+        for prop in props.iter().filter(|p| p.name == "FREEBUSY") {
+            if prop.param("FBTYPE").is_some_and(|t| t.eq_ignore_ascii_case("FREE")) {
+                continue;
+            }
+            busy.extend(
+                prop.value
+                    .split(',')
+                    .filter_map(|v| parse_period(v, default))
+                    .map(|(start, length)| Interval { start: start.utc(), end: start.utc() + length }),
+            );
+        }
+    }
+
+    merge(busy, range)
+}
+
+fn merge(mut intervals: Vec<Interval>, range: Interval) -> Vec<Interval> {
+    intervals.retain(|i| i.overlaps(&range));
+    intervals.sort();
+    let mut merged: Vec<Interval> = Vec::new();
+    for interval in intervals {
+        let interval = Interval { start: interval.start.max(range.start), end: interval.end.min(range.end) };
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
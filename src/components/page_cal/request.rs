@@ -0,0 +1,222 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::Deserialize;
+use super::ics::Interval;
+
+// A visitor's meeting request, turned into an invite file and an email draft in the
+// browser so no server is involved.
+
+// Preferred times a request may list
+pub const MAX_SLOTS: usize = 3;
+const MAX_TOPIC: usize = 120;
+const MAX_AGENDA: usize = 2000;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RequestSettings {
+    // Where requests go
+    pub to: String,
+    // Location written into in-person and video invites
+    pub in_person: String,
+    pub video: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeetingMode {
+    InPerson,
+    Video,
+}
+
+impl MeetingMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MeetingMode::InPerson => "In person",
+            MeetingMode::Video => "Video call",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Name,
+    Email,
+    Topic,
+    Agenda,
+    Times,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    pub field: Field,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeetingRequest {
+    pub name: String,
+    pub email: String,
+    pub topic: String,
+    pub agenda: String,
+    pub mode: MeetingMode,
+    // Picked in the week view, first choice first
+    pub slots: Vec<Interval>,
+    // Free text for times the week view does not show
+    pub other_times: String,
+}
+
+fn is_email(text: &str) -> bool {
+    let Some((local, domain)) = text.split_once('@') else { return false };
+    let allowed = |c: char| !c.is_whitespace() && !c.is_control() && !"<>()[],;:\"@\\".contains(c);
+    !local.is_empty()
+        && local.chars().all(allowed)
+        && domain.chars().all(allowed)
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !domain.contains("..")
+}
+
+// Text value escaping from RFC 5545
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+// Lines longer than 75 bytes continue on the next line after a space
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
+
+fn ics_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+// Everything but unreserved characters is percent-encoded, as mail clients expect in mailto links
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+impl MeetingRequest {
+    // Everything wrong with the request; empty when it can be sent
+    pub fn problems(&self) -> Vec<Problem> {
+        let mut problems = Vec::new();
+        let mut check = |ok: bool, field: Field, message: &str| {
+            if !ok {
+                problems.push(Problem { field, message: message.to_string() });
+            }
+        };
+        check(!self.name.trim().is_empty(), Field::Name, "Please enter your name.");
+        check(is_email(self.email.trim()), Field::Email, "Please enter a valid email address.");
+        check(!self.topic.trim().is_empty(), Field::Topic, "Please enter a topic.");
+        check(
+            self.topic.chars().count() <= MAX_TOPIC,
+            Field::Topic,
+            &format!("The topic is too long. Please keep it to {} characters.", MAX_TOPIC),
+        );
+        check(self.agenda.chars().count() <= MAX_AGENDA, Field::Agenda, "Please shorten the agenda.");
+        check(
+            !self.slots.is_empty() || !self.other_times.trim().is_empty(),
+            Field::Times,
+            "Please pick a time from the calendar or describe when you are free.",
+        );
+        check(self.slots.len() <= MAX_SLOTS, Field::Times, "Please pick at most three times.");
+        problems
+    }
+
+    pub fn location<'a>(&self, settings: &'a RequestSettings) -> &'a str {
+        match self.mode {
+            MeetingMode::InPerson => &settings.in_person,
+            MeetingMode::Video => &settings.video,
+        }
+    }
+
+    pub fn subject(&self) -> String {
+        format!("Meeting request: {}", self.topic.trim())
+    }
+
+    // `start–end` on one line, in the lab's zone
+    fn describe_slot(slot: &Interval, tz: Tz) -> String {
+        format!(
+            "{}–{} ({})",
+            slot.start.with_timezone(&tz).format("%a, %b %-d, %Y %H:%M"),
+            slot.end.with_timezone(&tz).format("%H:%M"),
+            tz.name()
+        )
+    }
+
+    // The structured text used for both the email and the invite description
+    pub fn body(&self, settings: &RequestSettings, tz: Tz) -> String {
+        let mut lines = vec![
+            format!("Name: {}", self.name.trim()),
+            format!("Email: {}", self.email.trim()),
+            format!("Topic: {}", self.topic.trim()),
+            format!("Meeting: {} ({})", self.mode.label(), self.location(settings)),
+            String::new(),
+            "Preferred times:".to_string(),
+        ];
+        lines.extend(self.slots.iter().enumerate().map(|(i, s)| format!("{}. {}", i + 1, Self::describe_slot(s, tz))));
+        if !self.other_times.trim().is_empty() {
+            lines.push(format!("Other times: {}", self.other_times.trim()));
+        }
+        if !self.agenda.trim().is_empty() {
+            lines.push(String::new());
+            lines.push("Agenda:".to_string());
+            lines.push(self.agenda.trim().to_string());
+        }
+        lines.join("\n")
+    }
+
+    pub fn mailto(&self, settings: &RequestSettings, tz: Tz) -> String {
+        format!(
+            "mailto:{}?subject={}&body={}",
+            settings.to,
+            percent_encode(&self.subject()),
+            percent_encode(&self.body(settings, tz))
+        )
+    }
+
+    // An invite for the first preferred time, with the others in the description.
+    // None without a picked time, since an invite needs one.
+    pub fn to_ics(&self, settings: &RequestSettings, tz: Tz, now: DateTime<Utc>) -> Option<String> {
+        let first = self.slots.first()?;
+        let uid = format!("{}-{}@meeting-request", now.timestamp_millis(), percent_encode(self.email.trim()));
+        let lines = [
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            concat!("PRODID:-//personal_website//Meeting request ", env!("CARGO_PKG_VERSION"), "//EN").to_string(),
+            "METHOD:REQUEST".to_string(),
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}", uid),
+            format!("DTSTAMP:{}", ics_time(now)),
+            format!("DTSTART:{}", ics_time(first.start)),
+            format!("DTEND:{}", ics_time(first.end)),
+            format!("SUMMARY:{}", escape(&self.subject())),
+            format!("LOCATION:{}", escape(self.location(settings))),
+            format!("DESCRIPTION:{}", escape(&self.body(settings, tz))),
+            format!("ORGANIZER;CN=\"{}\":mailto:{}", self.name.trim().replace('"', "'"), self.email.trim()),
+            format!("ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:{}", settings.to),
+            "STATUS:TENTATIVE".to_string(),
+            "END:VEVENT".to_string(),
+            "END:VCALENDAR".to_string(),
+        ];
+        Some(lines.iter().map(|l| fold(l)).collect())
+    }
+}
@@ -0,0 +1,144 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use super::ics::Interval;
+use super::super::timezone::resolve;
+
+// When meetings can be booked, written in assets/content/cal.toml. The same rules pick
+// the open slots in the week view and write the explanation above it.
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SlotType {
+    pub id: String,
+    pub label: String,
+    pub location: String,
+}
+
+// Recurring hours on the given weekdays, in the rules' time zone
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Window {
+    pub days: Vec<Weekday>,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub slot_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MeetingRules {
+    // IANA name, e.g. "America/New_York"
+    #[serde(deserialize_with = "zone")]
+    pub timezone: Tz,
+    #[serde(deserialize_with = "positive_minutes")]
+    pub slot_minutes: i64,
+    // Kept free before and after anything else on the calendar
+    #[serde(default)]
+    pub buffer_minutes: i64,
+    #[serde(default)]
+    pub blackout_dates: Vec<NaiveDate>,
+    pub windows: Vec<Window>,
+    pub slot_types: Vec<SlotType>,
+}
+
+// Both are checked when cal.toml is read, so the page never describes rules it does not follow
+fn zone<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Tz, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    name.parse().map_err(|_| D::Error::custom(format!("unknown timezone \"{}\"", name)))
+}
+
+fn positive_minutes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    let minutes = i64::deserialize(deserializer)?;
+    if minutes < 1 {
+        return Err(D::Error::custom(format!("slot_minutes must be at least 1, not {}", minutes)));
+    }
+    Ok(minutes)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slot {
+    pub interval: Interval,
+    pub slot_type: String,
+}
+
+impl MeetingRules {
+    pub fn tz(&self) -> Tz {
+        self.timezone
+    }
+
+    pub fn slot_type(&self, id: &str) -> Option<&SlotType> {
+        self.slot_types.iter().find(|t| t.id == id)
+    }
+
+    // `time` on `date` in the rules' zone, resolved like every other wall-clock time on the site
+    fn at(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+        resolve(&self.tz(), date.and_time(time))
+    }
+
+    // Slots inside `range` that do not come within the buffer of anything `busy`
+    pub fn slots(&self, busy: &[Interval], range: Interval) -> Vec<Slot> {
+        let tz = self.tz();
+        let length = Duration::minutes(self.slot_minutes);
+        let buffer = Duration::minutes(self.buffer_minutes.max(0));
+        let first = range.start.with_timezone(&tz).date_naive();
+        let last = range.end.with_timezone(&tz).date_naive();
+
+        let mut slots = Vec::new();
+        for date in first.iter_days().take_while(|d| *d <= last) {
+            if self.blackout_dates.contains(&date) {
+                continue;
+            }
+            for window in self.windows.iter().filter(|w| w.days.contains(&date.weekday())) {
+                let (start, end) = (self.at(date, window.start), self.at(date, window.end));
+                let mut time = start;
+                while time + length <= end {
+                    let slot = Interval { start: time, end: time + length };
+                    let guarded = Interval { start: slot.start - buffer, end: slot.end + buffer };
+                    if slot.start >= range.start && slot.end <= range.end && !busy.iter().any(|b| b.overlaps(&guarded)) {
+                        slots.push(Slot { interval: slot, slot_type: window.slot_type.clone() });
+                    }
+                    time += length;
+                }
+            }
+        }
+        slots.sort_by_key(|s| s.interval);
+        slots
+    }
+
+    // The rules in plain sentences, for the text above the calendar
+    pub fn describe(&self, today: NaiveDate) -> Vec<String> {
+        let mut lines = vec![format!("Meetings are {} minutes long.", self.slot_minutes)];
+        for slot_type in self.slot_types.iter() {
+            let hours: Vec<String> = self
+                .windows
+                .iter()
+                .filter(|w| w.slot_type == slot_type.id)
+                .map(|w| {
+                    let days: Vec<String> = w.days.iter().map(|d| d.to_string()).collect();
+                    format!("{} {}–{}", days.join(", "), w.start.format("%H:%M"), w.end.format("%H:%M"))
+                })
+                .collect();
+            if !hours.is_empty() {
+                lines.push(format!(
+                    "{}: {} ({} time), {}.",
+                    slot_type.label,
+                    hours.join("; "),
+                    self.timezone.name(),
+                    slot_type.location
+                ));
+            }
+        }
+        if self.buffer_minutes > 0 {
+            lines.push(format!("I keep {} minutes free before and after other commitments.", self.buffer_minutes));
+        }
+        let blackout: Vec<String> = self
+            .blackout_dates
+            .iter()
+            .filter(|d| **d >= today)
+            .map(|d| d.format("%b %-d, %Y").to_string())
+            .collect();
+        if !blackout.is_empty() {
+            lines.push(format!("No meetings on {}.", blackout.join(", ")));
+        }
+        lines
+    }
+}
@@ -1,121 +1,76 @@
 use dioxus::prelude::*;
-use super::css_preset::*;
+use super::theme::STYLES;
+use super::ui::{CardVariant, ExternalLink, LinkCard, PageShell, Pill, SectionHeader};
+use super::content;
 
-const CSS_HLINE: &str = "border-b border-gray-200 pb-6";
-const CSS_SECTION: &str = "text-2xl font-bold text-red-900 mb-4";
 const GITHUB_STAT_URL: &str = "https://github-readme-stats.vercel.app/api?username=Weiming-Hu&theme=default&show_icons=true&hide_rank=true";
-const CSS_LANGUAGE_TAG: &str = "inline-flex items-center px-2 py-1 text-xs font-medium bg-red-100 text-gray-500 rounded-full";
 
 #[component]
 pub fn Code() -> Element {
     rsx! {
-        div {
-            class: CSS_CONTENT_CONTAINER,
-            
+        PageShell {
+            // Header
             div {
-                class: CSS_CONTENT_CARD,
+                class: "mb-8 flex flex-col lg:flex-row lg:items-start lg:justify-between gap-6",
                 
-                // Header
                 div {
-                    class: "mb-8 flex flex-col lg:flex-row lg:items-start lg:justify-between gap-6",
+                    class: "lg:w-1/2",
+                    h1 {
+                        class: STYLES.page_title,
+                        "Code"
+                    }
+                    p {
+                        class: "text-gray-600 text-lg",
+                        "Open source contributions and research software"
+                    }
                     
+                    // Programming Languages
                     div {
-                        class: "lg:w-1/2",
-                        h1 {
-                            class: CSS_PAGE_TITLE,
-                            "Code"
-                        }
+                        class: "mt-4",
                         p {
-                            class: "text-gray-600 text-lg",
-                            "Open source contributions and research software"
+                            class: "text-sm text-gray-600 mb-2",
+                            ExternalLink { 
+                                href: "https://github.com/Weiming-Hu",
+                                class: "mb-2 hover:text-red-700 transition-colors",
+                                "Full profile on GitHub."
+                            }
+                            " Primary languages:"
                         }
-                        
-                        // Programming Languages
                         div {
-                            class: "mt-4",
-                            p {
-                                class: "text-sm text-gray-600 mb-2",
-                                a { 
-                                    href: "https://github.com/Weiming-Hu",
-                                    target: "_blank",
-                                    class: "mb-2 hover:text-red-700 transition-colors",
-                                    "Full profile on GitHub."
-                                }
-                                " Primary languages:"
-                            }
-                            div {
-                                class: "flex flex-wrap gap-2",
-                                span {
-                                    class: CSS_LANGUAGE_TAG,
-                                    "Python"
-                                }
-                                span {
-                                    class: CSS_LANGUAGE_TAG,
-                                    "R"
-                                }
-                                span {
-                                    class: CSS_LANGUAGE_TAG,
-                                    "C/C++"
-                                }
-                                span {
-                                    class: CSS_LANGUAGE_TAG,
-                                    "Rust"
-                                }
-                            }
+                            class: "flex flex-wrap gap-2",
+                            Pill { text: "Python" }
+                            Pill { text: "R" }
+                            Pill { text: "C/C++" }
+                            Pill { text: "Rust" }
                         }
                     }
-                    
-                    div {
-                        class: "lg:w-1/2 flex justify-center lg:justify-end",
-                        img {
-                            src: GITHUB_STAT_URL,
-                            alt: "GitHub Stats",
-                            style: "height: 200px; border: none;",
-                        }
+                }
+                
+                div {
+                    class: "lg:w-1/2 flex justify-center lg:justify-end",
+                    img {
+                        src: GITHUB_STAT_URL,
+                        alt: "GitHub Stats",
+                        style: "height: 200px; border: none;",
                     }
                 }
+            }
 
-                // GitHub Stats Section
+            // GitHub Stats Section
+            div {
+                class: "space-y-6",
+                
                 div {
-                    class: "space-y-6",
+                    class: "{STYLES.divider}",
+                    SectionHeader { title: "Selected Repositories" }
                     
-                    div {
-                        class: "{CSS_HLINE}",
-                        h2 {
-                            class: "{CSS_SECTION}",
-                            "Selected Repositories"
-                        }
-                        
-                        RepositoryCard {
-                            name: "AnalogsEnsemble",
-                            description: "The C++ and R packages for parallel ensemble forecasts using Analog Ensemble",
-                            language: "C++",
-                            url: "https://github.com/Weiming-Hu/AnalogsEnsemble",
-                            topics: vec!["r-package".to_string(), "forecasting".to_string(), "weather".to_string(), "uncertainty".to_string()]
-                        }
-                        
-                        RepositoryCard {
-                            name: "RAnEnExtra",
-                            description: "This is a R package that contains helpful functions for the RAnEn package.",
-                            language: "R",
-                            url: "https://github.com/Weiming-Hu/RAnEnExtra/",
-                            topics: vec!["ensemble-forecasting".to_string(), "verification".to_string(), "analysis".to_string()]
-                        }
-                        
+                    for repo in content::repositories() {
                         RepositoryCard {
-                            name: "DeepAnalogs",
-                            description: "Deep learning approach to analog ensemble forecasting using neural networks for spatiotemporal pattern recognition",
-                            language: "Python",
-                            url: "https://github.com/Weiming-Hu/DeepAnalogs",
-                            topics: vec!["deep-learning".to_string(), "pytorch".to_string(), "forecasting".to_string()]
-                        }
-                        
-                        RepositoryCard {
-                            name: "PyPIOMAS",
-                            description: "Module for downloading and converting PIOMAS data",
-                            language: "Python",
-                            url: "https://github.com/Weiming-Hu/PyPIOMAS",
-                            topics: vec!["PIOMAS".to_string(), "arctic".to_string()]
+                            name: repo.name.clone(),
+                            description: repo.description.clone(),
+                            language: repo.language.clone(),
+                            url: repo.url.clone(),
+                            topics: repo.topics.clone(),
                         }
                     }
                 }
@@ -124,7 +79,7 @@ pub fn Code() -> Element {
     }
 }
 #[component]
-fn RepositoryCard(
+pub(super) fn RepositoryCard(
     name: String,
     description: String,
     language: String,
@@ -132,15 +87,17 @@ fn RepositoryCard(
     topics: Vec<String>,
 ) -> Element {
     rsx! {
-        a {
-            href: "{url}",
-            target: "_blank",
-            class: "block mb-4 p-6 border border-gray-200 rounded-lg bg-white hover:bg-gray-50 hover:shadow-lg hover:border-red-300 transition-all duration-200 cursor-pointer transform hover:-translate-y-1",
+        LinkCard {
+            to: url,
+            label: name.clone(),
+            new_tab: true,
+            variant: CardVariant::Outline,
+            class: "mb-4",
             
             div {
                 class: "flex items-start justify-between mb-3",
                 h3 {
-                    class: "text-xl font-semibold text-gray-900 hover:text-red-700 transition-colors",
+                    class: "text-xl font-semibold text-gray-900 group-hover:text-red-700 transition-colors",
                     "{name}"
                 }
             }
@@ -166,10 +123,7 @@ fn RepositoryCard(
                 div {
                     class: "flex flex-wrap gap-1",
                     for topic in topics.iter().take(3) {
-                        span {
-                            class: CSS_LANGUAGE_TAG,
-                            "{topic}"
-                        }
+                        Pill { text: topic.clone() }
                     }
                 }
             }
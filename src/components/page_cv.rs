@@ -0,0 +1,231 @@
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use super::theme::STYLES;
+use super::ui::PageShell;
+use super::content::{self, MemberStatus, Program};
+use super::page_gaim::format_amount;
+use super::page_pub::fetch_publications;
+
+const PRINT_CSS: Asset = asset!("/assets/css/print.css");
+// Inlined into the downloaded file so it looks right without the site's stylesheets
+const EXPORT_CSS: &str = include_str!("../../assets/css/cv_export.css");
+
+const CSS_SECTION: &str = "text-xl font-bold text-red-900 uppercase tracking-wide border-b border-gray-300 mb-3 mt-8";
+const CSS_ENTRY: &str = "text-gray-800 leading-snug";
+const CSS_DETAIL: &str = "text-sm text-gray-600";
+const CSS_BUTTON: &str = "px-4 py-2 rounded border border-red-400 text-red-700 hover:bg-red-50 transition-colors cursor-pointer";
+
+// Saves the CV as a standalone HTML file, ready to open and print to PDF
+fn download_html() {
+    let css = serde_json::to_string(EXPORT_CSS).unwrap_or_default();
+    document::eval(&format!(
+        r#"
+        const cv = document.getElementById("cv").cloneNode(true);
+        cv.querySelectorAll("[data-no-print]").forEach((el) => el.remove());
+        const name = cv.querySelector("h1").textContent.trim();
+        const html = `<!DOCTYPE html>
+<html lang="en"><head><meta charset="utf-8"><title>${{name}} - Curriculum Vitae</title><style>${{{css}}}</style></head>
+<body>${{cv.innerHTML}}</body></html>`;
+        const url = URL.createObjectURL(new Blob([html], {{ type: "text/html" }}));
+        const link = document.createElement("a");
+        link.href = url;
+        link.download = name.replaceAll(" ", "_") + "_CV.html";
+        link.click();
+        URL.revokeObjectURL(url);
+        "#
+    ));
+}
+
+#[component]
+fn CvSection(title: String, children: Element) -> Element {
+    rsx! {
+        section {
+            h2 { class: CSS_SECTION, "{title}" }
+            ol {
+                class: "space-y-2",
+                {children}
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Cv() -> Element {
+    let home = content::home();
+    let mut publications = use_signal(|| None);
+
+    use_effect(move || {
+        spawn_local(async move {
+            let mut rows = fetch_publications().await.unwrap_or_default();
+            rows.sort_by(|a, b| b.year.cmp(&a.year));
+            publications.set(Some(rows));
+        });
+    });
+
+    let grants = content::grants();
+    let talks = content::talks();
+    let mut courses: Vec<_> = content::courses().iter().collect();
+    courses.sort_by_key(|c| std::cmp::Reverse(c.latest_term()));
+    let repositories = content::repositories();
+    let advisees: Vec<_> = content::members().iter().filter(|m| m.program != Program::Pi).collect();
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: PRINT_CSS }
+
+        PageShell {
+            id: "cv",
+            div {
+                "data-no-print": true,
+                class: "flex justify-end gap-3 mb-4",
+                button {
+                    r#type: "button",
+                    class: CSS_BUTTON,
+                    onclick: move |_| {
+                        document::eval("window.print();");
+                    },
+                    i { class: "fa-solid fa-print mr-2" }
+                    "Print"
+                }
+                button {
+                    r#type: "button",
+                    class: CSS_BUTTON,
+                    onclick: move |_| download_html(),
+                    i { class: "fa-solid fa-download mr-2" }
+                    "Download HTML"
+                }
+            }
+
+            div {
+                class: "text-center mb-6",
+                h1 { class: STYLES.page_title, "{home.name}" }
+                p { class: "text-gray-600", "Curriculum Vitae" }
+                for line in home.address.iter() {
+                    p { class: CSS_DETAIL, "{line}" }
+                }
+                p {
+                    class: CSS_DETAIL,
+                    for (i, link) in home.social.iter().enumerate() {
+                        if i > 0 { " · " }
+                        a { href: "{link.url}", class: STYLES.link, "{link.title}" }
+                    }
+                }
+            }
+
+            match publications() {
+                None => rsx! {
+                    section {
+                        h2 { class: CSS_SECTION, "Publications" }
+                        p { class: "text-gray-400", "Loading ..." }
+                    }
+                },
+                Some(rows) if rows.is_empty() => rsx! {},
+                Some(rows) => rsx! {
+                    CvSection {
+                        title: "Publications",
+                        for p in rows {
+                            li {
+                                class: CSS_ENTRY,
+                                "{p.authors} ({p.year}). "
+                                if p.website.is_empty() {
+                                    "{p.title}. "
+                                } else {
+                                    a { href: "{p.website}", class: STYLES.link, "{p.title}" }
+                                    ". "
+                                }
+                                i { "{p.journal}" }
+                                "."
+                            }
+                        }
+                    }
+                },
+            }
+
+            if !grants.is_empty() {
+                CvSection {
+                    title: "Grants",
+                    for grant in grants {
+                        li {
+                            class: CSS_ENTRY,
+                            p { "{grant.title}" }
+                            p {
+                                class: CSS_DETAIL,
+                                "{grant.agency} #{grant.award_number}, {grant.role}, {grant.start.format(\"%m/%Y\")}–{grant.end.format(\"%m/%Y\")}"
+                                if let Some(amount) = grant.amount {
+                                    ", {format_amount(amount)}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !talks.is_empty() {
+                CvSection {
+                    title: "Talks and Presentations",
+                    for talk in talks {
+                        li {
+                            class: CSS_ENTRY,
+                            "{talk.title}. "
+                            i { "{talk.event}" }
+                            ", {talk.location}, {talk.date.format(\"%B %Y\")}."
+                        }
+                    }
+                }
+            }
+
+            if !courses.is_empty() {
+                CvSection {
+                    title: "Teaching",
+                    for course in courses {
+                        li {
+                            class: CSS_ENTRY,
+                            "{course.code}: {course.title}"
+                            p {
+                                class: CSS_DETAIL,
+                                {
+                                    let mut terms = course.terms.clone();
+                                    terms.sort_by(|a, b| b.cmp(a));
+                                    terms.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", ")
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !advisees.is_empty() {
+                CvSection {
+                    title: "Advising",
+                    for member in advisees {
+                        li {
+                            class: CSS_ENTRY,
+                            "{member.first_name} {member.last_name}, {member.program.degree()}"
+                            match (member.status, member.graduation, member.cohort) {
+                                (MemberStatus::Alumni, Some(year), _) => rsx! { " ({year})" },
+                                (MemberStatus::Current, _, Some(cohort)) => rsx! { " ({cohort}–present)" },
+                                _ => rsx! {},
+                            }
+                            if let Some(placement) = &member.placement {
+                                p { class: CSS_DETAIL, "First placement: {placement}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !repositories.is_empty() {
+                CvSection {
+                    title: "Software",
+                    for repo in repositories {
+                        li {
+                            class: CSS_ENTRY,
+                            a { href: "{repo.url}", class: STYLES.link, "{repo.name}" }
+                            " ({repo.language}): {repo.description}"
+                        }
+                    }
+                }
+            }
+
+        }
+    }
+}
@@ -1,52 +1,381 @@
 use dioxus::prelude::*;
-use super::css_preset::*;
+use super::theme::STYLES;
+use super::timezone::{self, LAB_TZ};
+use super::ui::{Callout, CalloutTone, Card, ExternalLink, LinkCard, PageShell};
+use super::page_res::sheet::Audience;
+use super::routes::Route;
+use super::content::{self, Grant, Member, MemberStatus, Program, SocialLink, Thesis};
+use super::markdown::{Markdown, ProseStyle};
+use super::page_news::LatestNews;
 
 const UGA_LOGO: Asset = asset!("/assets/imgs/uga_logo.png");
 const RESEACH_IMG_AR: Asset = asset!("/assets/imgs/research_bg_AR.png");
-const RESEACH_IMG_Arc: Asset = asset!("/assets/imgs/research_bg_Arctic.jpg");
-const RESEACH_IMG_Power: Asset = asset!("/assets/imgs/research_bg_Power.jpg");
+const RESEACH_IMG_ARC: Asset = asset!("/assets/imgs/research_bg_Arctic.jpg");
+const RESEACH_IMG_POWER: Asset = asset!("/assets/imgs/research_bg_Power.jpg");
 const GROUP_PHOTO: Asset = asset!("/assets/imgs/group.jpeg");
 
-const CSS_RESEARCH_CARD: &str = 
-    "block mb-4 p-4 border-l-4 border-red-400 rounded-r-lg bg-white \
-     hover:bg-gray-50 hover:shadow-lg hover:border-red-500 transition-all duration-200 \
-     cursor-pointer transform hover:-translate-y-1 relative overflow-hidden min-h-[120px]";
+const ABOUT_STYLE: ProseStyle = ProseStyle {
+    em: "hover:text-red-700 transition-colors",
+    ..ProseStyle::DEFAULT
+};
+
+// Images named by `image` in assets/content/lab.toml
+pub(super) fn theme_image(name: &str) -> Option<Asset> {
+    match name {
+        "ar" => Some(RESEACH_IMG_AR),
+        "power" => Some(RESEACH_IMG_POWER),
+        "arctic" => Some(RESEACH_IMG_ARC),
+        _ => None,
+    }
+}
+
+// Logos named by `logo` in assets/content/grants.toml
+fn grant_logo(name: &str) -> Option<Asset> {
+    match name {
+        "uga" => Some(UGA_LOGO),
+        _ => None,
+    }
+}
+
+// $1,250,000
+pub(super) fn format_amount(amount: u64) -> String {
+    let digits = amount.to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    format!("${}", out)
+}
 
 const PPL_IMAGE_HU: Asset = asset!("/assets/ppl/hu.png");
 const CSS_MEMBER_TEXT: &str = "text-gray-700 leading-relaxed";
+const CSS_PEOPLE_GROUP: &str = "text-2xl font-bold text-red-900 mb-4";
+
+pub(super) const MEMBER_STYLE: ProseStyle = ProseStyle {
+    paragraph: CSS_MEMBER_TEXT,
+    ..ProseStyle::DEFAULT
+};
+
+// Photos named by `photo` in assets/content/members.toml
+pub(super) fn member_photo(name: &str) -> Option<Asset> {
+    match name {
+        "hu" => Some(PPL_IMAGE_HU),
+        _ => None,
+    }
+}
+
+// Sections of the People list, in display order. Alumni have their own table.
+const PEOPLE_SECTIONS: [(&str, Program); 4] = [
+    ("Principal Investigator", Program::Pi),
+    ("PhD Students", Program::Phd),
+    ("MS Students", Program::Ms),
+    ("Undergraduate Researchers", Program::Undergrad),
+];
+
+fn members_in(program: Program) -> Vec<&'static Member> {
+    let mut members: Vec<&Member> = content::members()
+        .iter()
+        .filter(|m| m.status == MemberStatus::Current && m.program == program)
+        .collect();
+    members.sort_by_key(|m| (m.cohort, m.last_name.clone()));
+    members
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AlumniSort {
+    Name,
+    Graduation,
+    Placement,
+}
+
+fn sorted_alumni(sort: AlumniSort, descending: bool) -> Vec<&'static Member> {
+    let mut alumni: Vec<&Member> = content::members()
+        .iter()
+        .filter(|m| m.status == MemberStatus::Alumni)
+        .collect();
+    match sort {
+        AlumniSort::Name => alumni.sort_by_key(|m| (m.last_name.clone(), m.first_name.clone())),
+        AlumniSort::Graduation => alumni.sort_by_key(|m| (m.graduation, m.last_name.clone())),
+        AlumniSort::Placement => alumni.sort_by_key(|m| (m.placement.clone(), m.last_name.clone())),
+    }
+    if descending {
+        alumni.reverse();
+    }
+    alumni
+}
+
+const CSS_TABLE_HEAD: &str = "text-left text-sm font-semibold text-gray-700 px-3 py-2 border-b-2 border-red-600";
+const CSS_TABLE_CELL: &str = "text-gray-700 px-3 py-2 border-b border-gray-200 align-top";
+
+#[component]
+fn AlumniTable() -> Element {
+    // Most recent graduates first
+    let mut sort = use_signal(|| (AlumniSort::Graduation, true));
+    let mut grouped = use_signal(|| false);
+
+    let (key, descending) = sort();
+    let alumni = sorted_alumni(key, descending);
+    if alumni.is_empty() {
+        return rsx! {};
+    }
+    let groups: Vec<(Option<Program>, Vec<&Member>)> = if grouped() {
+        [Program::Phd, Program::Ms, Program::Undergrad, Program::Pi]
+            .into_iter()
+            .map(|p| (Some(p), alumni.iter().copied().filter(|m| m.program == p).collect::<Vec<_>>()))
+            .filter(|(_, members)| !members.is_empty())
+            .collect()
+    } else {
+        vec![(None, alumni)]
+    };
+
+    let header = move |label: &'static str, column: AlumniSort| {
+        let arrow = match sort() {
+            (k, true) if k == column => " ▼",
+            (k, false) if k == column => " ▲",
+            _ => "",
+        };
+        rsx! {
+            th {
+                class: CSS_TABLE_HEAD,
+                button {
+                    class: "hover:text-red-700 transition-colors cursor-pointer",
+                    onclick: move |_| {
+                        let (k, descending) = sort();
+                        sort.set((column, if k == column { !descending } else { column == AlumniSort::Graduation }));
+                    },
+                    "{label}{arrow}"
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "space-y-4",
+            div {
+                class: "flex flex-wrap items-center justify-between gap-2",
+                h3 { class: CSS_PEOPLE_GROUP, "Alumni" }
+                label {
+                    class: "text-sm text-gray-600 flex items-center gap-2 cursor-pointer",
+                    input {
+                        r#type: "checkbox",
+                        checked: grouped(),
+                        onchange: move |e| grouped.set(e.checked()),
+                    }
+                    "Group by degree"
+                }
+            }
+            div {
+                class: "overflow-x-auto",
+                table {
+                    class: "w-full border-collapse",
+                    thead {
+                        tr {
+                            {header("Name", AlumniSort::Name)}
+                            th { class: CSS_TABLE_HEAD, "Degree" }
+                            {header("Graduated", AlumniSort::Graduation)}
+                            th { class: CSS_TABLE_HEAD, "Thesis" }
+                            {header("First Placement", AlumniSort::Placement)}
+                        }
+                    }
+                    for (program, members) in groups {
+                        tbody {
+                            if let Some(program) = program {
+                                tr {
+                                    td {
+                                        class: "pt-4 pb-1 px-3 font-bold text-red-900",
+                                        colspan: "5",
+                                        "{program.degree()}"
+                                    }
+                                }
+                            }
+                            for member in members {
+                                tr {
+                                    td {
+                                        class: CSS_TABLE_CELL,
+                                        Link {
+                                            to: Route::MemberProfile { id: member.id.clone() },
+                                            class: STYLES.link,
+                                            "{member.first_name} {member.last_name}"
+                                        }
+                                    }
+                                    td { class: CSS_TABLE_CELL, "{member.program.degree()}" }
+                                    td {
+                                        class: CSS_TABLE_CELL,
+                                        {member.graduation.map(|y| y.to_string()).unwrap_or_default()}
+                                    }
+                                    td {
+                                        class: "{CSS_TABLE_CELL} italic",
+                                        match &member.thesis {
+                                            Some(Thesis { title, url: Some(url) }) => rsx! {
+                                                ExternalLink { href: "{url}", class: STYLES.link, "{title}" }
+                                            },
+                                            Some(Thesis { title, url: None }) => rsx! { "{title}" },
+                                            None => rsx! {},
+                                        }
+                                    }
+                                    td {
+                                        class: CSS_TABLE_CELL,
+                                        {member.placement.clone().unwrap_or_default()}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub(super) fn GrantCard(grant: Grant) -> Element {
+    rsx! {
+        Card {
+            id: "grant-{grant.id}",
+            class: "flex gap-4 items-start",
+            if let Some(logo) = grant.logo.as_deref().and_then(grant_logo) {
+                img {
+                    src: logo,
+                    alt: "{grant.agency}",
+                    class: "h-12 w-12 object-contain flex-shrink-0"
+                }
+            }
+            div {
+                class: "flex-1",
+                h4 {
+                    class: "font-semibold text-gray-900",
+                    if let Some(url) = &grant.url {
+                        ExternalLink { href: "{url}", class: STYLES.link, "{grant.title}" }
+                    } else {
+                        "{grant.title}"
+                    }
+                }
+                p {
+                    class: "text-gray-700",
+                    "{grant.agency} #{grant.award_number} · {grant.role}"
+                }
+                p {
+                    class: "text-sm text-gray-500",
+                    "{grant.start.format(\"%b %Y\")} – {grant.end.format(\"%b %Y\")}"
+                    if let Some(amount) = grant.amount {
+                        " · {format_amount(amount)}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn FundingSection() -> Element {
+    let today = timezone::today(LAB_TZ);
+    let (active, completed): (Vec<&Grant>, Vec<&Grant>) = content::grants().iter().partition(|g| g.is_active(today));
+
+    if active.is_empty() && completed.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "mb-12 space-y-6",
+            h2 {
+                class: "text-3xl font-bold text-gray-800 mb-6 pb-2",
+                "Funding"
+            }
+            p {
+                class: "text-gray-600 text-lg",
+                "We gratefully acknowledge the support of our sponsors."
+            }
+            for (title, grants) in [("Active", active), ("Completed", completed)] {
+                if !grants.is_empty() {
+                    div {
+                        class: "space-y-4",
+                        h3 { class: CSS_PEOPLE_GROUP, "{title}" }
+                        for grant in grants {
+                            GrantCard { grant: grant.clone() }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
 
 #[component]
 fn TeamMember(
+    id: String,
     first_name: String,
     last_name: String,
     role: String,
-    image_path: String,
+    image_path: Option<String>,
+    #[props(default)]
+    cohort: Option<i32>,
+    #[props(default)]
+    links: Vec<SocialLink>,
     description: Element,
 ) -> Element {
     rsx! {
         div {
-            class: "bg-white p-4 rounded-lg shadow-md border border-gray-200 flex flex-col md:flex-row gap-8",
+            class: "bg-surface p-4 rounded-lg shadow-md border border-gray-200 flex flex-col md:flex-row gap-8",
             // Left column: Image and name
             div {
                 class: "flex flex-col items-center justify-center md:items-center md:justify-center md:w-1/4 md:h-full md:self-center",
-                img {
-                    src: "{image_path}",
-                    alt: "{first_name} {last_name}",
-                    class: "aspect-square w-full h-auto max-w-[250px] max-h-[250px] rounded-full object-cover shadow-md m-4"
+                if let Some(image_path) = image_path {
+                    img {
+                        src: "{image_path}",
+                        alt: "{first_name} {last_name}",
+                        class: "aspect-square w-full h-auto max-w-[250px] max-h-[250px] rounded-full object-cover shadow-md m-4"
+                    }
+                } else {
+                    div {
+                        class: "aspect-square w-full max-w-[250px] rounded-full shadow-md m-4 bg-red-100 text-red-700 text-5xl font-bold flex items-center justify-center",
+                        "{first_name.chars().next().unwrap_or_default()}{last_name.chars().next().unwrap_or_default()}"
+                    }
                 }
                 h3 {
                     class: "text-xl font-semibold text-gray-800 text-center md:text-left",
-                    "{first_name} {last_name}"
+                    Link {
+                        to: Route::MemberProfile { id: id.clone() },
+                        class: "hover:text-red-700 transition-colors",
+                        "{first_name} {last_name}"
+                    }
                 }
                 p {
                     class: "text-red-600 font-medium text-center md:text-left",
                     "{role}"
                 }
+                if let Some(cohort) = cohort {
+                    p {
+                        class: "text-sm text-gray-500 text-center md:text-left",
+                        "Joined {cohort}"
+                    }
+                }
+                div {
+                    class: "flex justify-center space-x-4 mt-2",
+                    for link in links {
+                        ExternalLink {
+                            href: "{link.url}",
+                            class: "text-gray-600 hover:text-red-600 transition-colors text-xl",
+                            title: "{link.title}",
+                            i { class: "{link.icon}", aria_hidden: "true" }
+                            span { class: "sr-only", "{link.title}" }
+                        }
+                    }
+                }
             }
             // Right column: Description
             div {
                 class: "flex-1 md:w-3/4 md:h-full md:self-center space-y-3 text-lg",
                 {description}
+                Link {
+                    to: Route::MemberProfile { id },
+                    class: "inline-block text-base {STYLES.link}",
+                    "Full profile →"
+                }
             }
         }
     }
@@ -54,251 +383,189 @@ fn TeamMember(
 
 #[component]
 pub fn Lab() -> Element {
+    let content = content::lab();
+
     rsx! {
-        div {
-            class: format!("{} relative z-10", CSS_CONTENT_CONTAINER),
-            
+        PageShell {
             div {
-                class: CSS_CONTENT_CARD,
-                
+                class: "mb-8 space-y-4 mb-6 border-b-2 border-red-600 flex flex-col sm:flex-row sm:justify-between sm:items-center",
                 div {
-                    class: "mb-8 space-y-4 mb-6 border-b-2 border-red-600 flex flex-col sm:flex-row sm:justify-between sm:items-center",
-                    div {
-                        class: "flex-1",
-                        h1 {
-                            class: CSS_PAGE_TITLE,
-                            "Lab for "
-                            span {
-                                style: "background: linear-gradient(90deg, #000000, #ba0c2f, #e4002b, #000000, #e4002b, #ffd200); -webkit-background-clip: text; -webkit-text-fill-color: transparent; background-clip: text; color: transparent;",
-                                "Geoinformatics and AI Modeling"
-                            }
-                            " (GAIM)"
-                        }
-                    }
-                    div {
-                        class: "flex justify-center sm:justify-end sm:flex-shrink-0 sm:ml-8 mt-4 sm:mt-0",
-                        img {
-                            src: UGA_LOGO,
-                            alt: "University of Georgia Logo",
-                            class: "h-24 w-auto object-contain"
-                        }
+                    class: "flex-1",
+                    h1 {
+                        class: STYLES.page_title,
+                        "Lab for "
+                        span { class: "gradient-text", "Geoinformatics and AI Modeling" }
+                        " (GAIM)"
                     }
                 }
-
-                // About Us Section
                 div {
-                    class: "mb-12 space-y-6",
-                    h2 {
-                        class: "text-3xl font-bold text-gray-800",
-                        "About Us"
+                    class: "flex justify-center sm:justify-end sm:flex-shrink-0 sm:ml-8 mt-4 sm:mt-0",
+                    img {
+                        src: UGA_LOGO,
+                        alt: "University of Georgia Logo",
+                        class: "h-24 w-auto object-contain"
                     }
+                }
+            }
 
+            // About Us Section
+            div {
+                class: "mb-12 space-y-6",
+                h2 {
+                    class: "text-3xl font-bold text-gray-800",
+                    "About Us"
+                }
+
+                div {
+                    class: "w-full rounded-lg shadow-md overflow-hidden group",
+                    style: "aspect-ratio: 28/9;",
                     div {
-                        class: "w-full rounded-lg shadow-md overflow-hidden group",
-                        style: "aspect-ratio: 28/9;",
-                        div {
-                            class: "w-full h-full relative",
-                            img {
-                                src: GROUP_PHOTO,
-                                alt: "GAIM group photo",
-                                class: "w-full h-full object-cover transform transition-all duration-500 ease-out group-hover:scale-102",
-                                style: format!("object-position: center {}%; filter: contrast(1.06) saturate(1.08) brightness(1.02);", 0),
-                            }
+                        class: "w-full h-full relative",
+                        img {
+                            src: GROUP_PHOTO,
+                            alt: "GAIM group photo",
+                            class: "w-full h-full object-cover transform transition-all duration-500 ease-out group-hover:scale-102",
+                            style: format!("object-position: center {}%; filter: contrast(1.06) saturate(1.08) brightness(1.02);", 0),
+                        }
 
-                            div {
-                                class: "absolute inset-0 pointer-events-none rounded-lg",
-                                style: "background: linear-gradient(120deg, rgba(13,102,146,0.18), rgba(234,96,54,0.18)); mix-blend-mode: soft-light;",
-                            }
+                        div {
+                            class: "absolute inset-0 pointer-events-none rounded-lg",
+                            style: "background: linear-gradient(120deg, rgba(13,102,146,0.18), rgba(234,96,54,0.18)); mix-blend-mode: soft-light;",
+                        }
 
-                            div {
-                                class: "absolute inset-0 pointer-events-none rounded-lg",
-                                style: "background: radial-gradient(ellipse at center, rgba(0,0,0,0) 55%, rgba(0,0,0,0.32) 100%);",
-                            }
+                        div {
+                            class: "absolute inset-0 pointer-events-none rounded-lg",
+                            style: "background: radial-gradient(ellipse at center, rgba(0,0,0,0) 55%, rgba(0,0,0,0.32) 100%);",
                         }
                     }
-                    
-                    p {
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        "We focus on advancing geospatial analytics and predictive
-                        modeling by combining Artificial Intelligence (AI) with Geoinformatics. "
-                        "AI means learning from data to uncover patterns, make predictions, and quantify uncertainty
-                        in ways that adapt and scale. Geoinformatics grounds these capabilities in space and time,
-                        connecting models to the physical world through remote sensing and GIS."
-                    }
+                }
+                
+                for paragraph in content.about.iter() {
+                    Markdown { text: paragraph.clone(), style: ABOUT_STYLE }
+                }
 
-                    p {
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        "We develop scalable, uncertainty-aware methods to study and forecast phenomena such as
-                        extreme events and renewable energy (wind and solar photovoltaic) production. "
-                        i {
-                            class: "hover:text-red-700 transition-colors",
-                            "To integrate Geoinformatics and AI is not just to interpret the Earth as it is,
-                            but to anticipate how it will evolve in the future, both near and far."
+                Callout {
+                    tone: CalloutTone::Notice,
+                    class: "flex justify-center max-w-2xl items-center gap-3 mx-auto",
+                    svg {
+                        xmlns: "http://www.w3.org/2000/svg",
+                        class: "h-6 w-6 text-yellow-500 flex-shrink-0",
+                        fill: "none",
+                        view_box: "0 0 24 24",
+                        stroke: "currentColor",
+                        stroke_width: "2",
+                        path {
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round",
+                            d: "M13 16h-1v-4h-1m1-4h.01M12 20a8 8 0 100-16 8 8 0 000 16z"
                         }
                     }
-
-                    div {
-                        class: "bg-yellow-50 border-l-4 border-yellow-400 p-4 rounded-lg flex justify-center max-w-2xl items-center gap-3 mx-auto",
-                        svg {
-                            xmlns: "http://www.w3.org/2000/svg",
-                            class: "h-6 w-6 text-yellow-500 flex-shrink-0",
-                            fill: "none",
-                            view_box: "0 0 24 24",
-                            stroke: "currentColor",
-                            stroke_width: "2",
-                            path {
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                d: "M13 16h-1v-4h-1m1-4h.01M12 20a8 8 0 100-16 8 8 0 000 16z"
-                            }
+                    span {
+                        class: "text-yellow-900 font-medium text-lg",
+                        "Students and early-career scholars: check out our resources for "
+                        Link {
+                            to: Route::ResourcesFor { audience: Audience::Undergrad.slug().to_string() },
+                            class: STYLES.link,
+                            "undergraduates"
                         }
-                        span {
-                            class: "text-yellow-900 font-medium text-lg",
-                            "Students and early-career scholars: check out "
-                            a {
-                                href: "/res",
-                                class: CSS_LINK_TEXT,
-                                "our resource page"
-                            }
-                            "!"
+                        ", "
+                        Link {
+                            to: Route::ResourcesFor { audience: Audience::Grad.slug().to_string() },
+                            class: STYLES.link,
+                            "graduate students"
+                        }
+                        ", and "
+                        Link {
+                            to: Route::ResourcesFor { audience: Audience::Postdoc.slug().to_string() },
+                            class: STYLES.link,
+                            "postdocs"
                         }
+                        "!"
                     }
                 }
+            }
 
-                // Research Themes Section
-                div {
-                    class: "mb-12 space-y-6",
-                    h2 {
-                        class: "text-3xl font-bold text-gray-800 mb-6 pb-2",
-                        "Research Themes"
-                    }
+            // News Section
+            div {
+                class: "mb-12",
+                LatestNews {}
+            }
 
-                    div {
-                        class: "grid gap-6 md:grid-cols-1 lg:grid-cols-3",
-                        
-                        // Theme 1: Extreme Events
-                        a {
-                            href: "https://doi.org/10.1175/MWR-D-22-0268.1",
-                            target: "_blank",
-                            class: CSS_RESEARCH_CARD,
-                            img {
-                                src: RESEACH_IMG_AR,
-                                alt: "Extreme Events Research",
-                                class: "absolute inset-0 w-full h-full object-cover rounded-r-lg opacity-30"
-                            }
-                            div {
-                                class: "relative z-10 flex items-center justify-center h-full",
-                                span {
-                                    class: "text-lg font-medium text-gray-800 text-center px-4",
-                                    "Resilience against extreme events with better forecasts: heatwaves and precipitation"
-                                }
-                            }
-                        }
+            // Research Themes Section
+            div {
+                class: "mb-12 space-y-6",
+                h2 {
+                    class: "text-3xl font-bold text-gray-800 mb-6 pb-2",
+                    "Research Themes"
+                }
 
-                        // Theme 2: Energy Market
-                        a {
-                            href: "https://www.sciencedirect.com/science/article/pii/S2352340922000361",
-                            target: "_blank",
-                            class: CSS_RESEARCH_CARD,
-                            img {
-                                src: RESEACH_IMG_Power,
-                                alt: "Energy Market Research",
-                                class: "absolute inset-0 w-full h-full object-cover rounded-r-lg opacity-30"
+                div {
+                    class: "grid gap-6 md:grid-cols-1 lg:grid-cols-3",
+                    
+                    for theme in content.themes.iter() {
+                        LinkCard {
+                            to: Route::ThemePage { slug: theme.slug.clone() },
+                            label: theme.title.clone(),
+                            class: "mb-4 overflow-hidden min-h-[120px]",
+                            if let Some(image) = theme_image(&theme.image) {
+                                img {
+                                    src: image,
+                                    alt: "{theme.alt}",
+                                    class: "absolute inset-0 w-full h-full object-cover rounded-r-lg opacity-30 pointer-events-none"
+                                }
                             }
                             div {
-                                class: "relative z-10 flex items-center justify-center h-full",
+                                class: "relative flex items-center justify-center h-full pointer-events-none",
                                 span {
                                     class: "text-lg font-medium text-gray-800 text-center px-4",
-                                    "AI for energy market forecasting and renewable energy production optimization"
+                                    "{theme.title}"
                                 }
                             }
                         }
+                    }
+                }
+            }
 
-                        // Theme 3: Arctic Sustainability
-                        a {
-                            href: "https://doi.org/10.1080/19475683.2024.2380678",
-                            target: "_blank",
-                            class: CSS_RESEARCH_CARD,
-                            img {
-                                src: RESEACH_IMG_Arc,
-                                alt: "Arctic Sustainability Research",
-                                class: "absolute inset-0 w-full h-full object-cover rounded-r-lg opacity-30"
-                            }
-                            div {
-                                class: "relative z-10 flex items-center justify-center h-full",
-                                span {
-                                    class: "text-lg font-medium text-gray-800 text-center px-4",
-                                    "Sustainable accessibility in the Arctic with geospatial modeling"
+            // People Section
+            div {
+                class: "mb-12 space-y-6",
+                h2 {
+                    class: "text-3xl font-bold text-gray-800 mb-6 pb-2",
+                    "People"
+                }
+
+                for (title, program) in PEOPLE_SECTIONS {
+                    {
+                        let members = members_in(program);
+                        rsx! {
+                            if !members.is_empty() {
+                                div {
+                                    class: "space-y-8",
+                                    h3 { class: CSS_PEOPLE_GROUP, "{title}" }
+                                    for member in members {
+                                        TeamMember {
+                                            id: member.id.clone(),
+                                            first_name: member.first_name.clone(),
+                                            last_name: member.last_name.clone(),
+                                            role: member.role.clone(),
+                                            image_path: member.photo.as_deref().and_then(member_photo).map(|a| a.to_string()),
+                                            cohort: member.cohort,
+                                            links: member.links.clone(),
+                                            description: rsx! {
+                                                Markdown { text: member.bio.clone(), style: MEMBER_STYLE }
+                                            },
+                                        }
+                                    }
                                 }
                             }
                         }
                     }
                 }
 
-                // People Section
-                // div {
-                //     class: "mb-12 space-y-6",
-                //     h2 {
-                //         class: "text-3xl font-bold text-gray-800 mb-6 pb-2",
-                //         "People"
-                //     }
-
-                //     div {
-                //         class: "space-y-8",
-                        
-                //         TeamMember {
-                //             first_name: "Weiming".to_string(),
-                //             last_name: "Hu".to_string(),
-                //             role: "Principal Investigator".to_string(),
-                //             image_path: PPL_IMAGE_HU,
-                //             description: rsx!{ 
-                //                 p {  
-                //                     class: CSS_MEMBER_TEXT,
-                //                     "My research spans Geographic Information Science, Machine Learning, and environmental forecasting, "
-                //                     "with expertise in extreme and rare event prediction (heatwaves, precipitation, flooding), renewable energy "
-                //                     "forecasting (solar and wind), and uncertainty quantification in spatio-temporal big data. "
-                //                     "I have developed "
-                //                     a {
-                //                         href: "https://cw3e.ucsd.edu/ml_forecasts/",
-                //                         target: "_blank",
-                //                         class: CSS_LINK_TEXT,
-                //                         "Deep Learning"
-                //                     }
-                //                     " and "
-                //                     a {
-                //                         href: "http://weiming.uga.edu/AnalogsEnsemble/2018/12/14/AnEn-explained.html",
-                //                         target: "_blank",
-                //                         class: CSS_LINK_TEXT,
-                //                         "Analog Ensemble"
-                //                     }
-                //                     " methods to improve predictions over different time scales including weather and sub-seasonal-to-seasonal. "
-                //                 },
-
-                //                 p { 
-                //                     class: CSS_MEMBER_TEXT,
-                //                     "Prior to joining UGA, I conducted research at the "
-                //                     a {
-                //                         href: "https://cw3e.ucsd.edu/",
-                //                         target: "_blank",
-                //                         class: CSS_LINK_TEXT,
-                //                         "Center of Western Water and Weather Extremes"
-                //                     }
-                //                     " at Scripps, UC San Diego, and then held a faculty position at "
-                //                     a {
-                //                         href: "https://www.jmu.edu/cise/index.shtml",
-                //                         target: "_blank",
-                //                         class: CSS_LINK_TEXT,
-                //                         "James Madison University"
-                //                     }
-                //                     ". "
-                //                     "My work bridges computational problem-solving with environmental applications, "
-                //                     "often in collaboration with interdisciplinary teams and operational forecasting agencies."
-                //                  }
-                //             }
-                //         }
-                //     }
-                // }
+                AlumniTable {}
             }
+
+            FundingSection {}
         }
     }
 }
@@ -1,41 +1,86 @@
 use dioxus::prelude::*;
-use super::css_preset::*;
+use super::theme::STYLES;
+use super::ui::PageShell;
+use super::page_res::fetch_resources;
+use super::page_res::sheet::{sheet_warnings, SheetWarning};
+use wasm_bindgen_futures::spawn_local;
 
 #[component]
 pub fn Info() -> Element {
+    let mut warnings = use_signal(|| None::<Vec<SheetWarning>>);
+
+    use_effect({
+        move || {
+            spawn_local(async move {
+                if let Some(sheet) = fetch_resources().await {
+                    warnings.set(Some(sheet_warnings(&sheet)));
+                }
+            });
+        }
+    });
+
     rsx! {
-        div {
-            class: format!("{} relative z-10", CSS_CONTENT_CONTAINER),
-            
+        PageShell {
             div {
-                class: CSS_CONTENT_CARD,
-                
-                div {
-                    class: "mb-8",
-                    h1 {
-                        class: CSS_PAGE_TITLE,
-                        "Information"
+                class: "mb-8",
+                h1 {
+                    class: STYLES.page_title,
+                    "Information"
+                }
+
+                p {
+                    class: "text-gray-600 italic",
+                    "Website version: "
+                    span {
+                        class: "font-mono font-semibold",
+                        "{env!(\"CARGO_PKG_VERSION\")}"
                     }
+                }
 
-                    p {
-                        class: "text-gray-600 italic",
-                        "Website version: "
-                        span { 
-                            class: "font-mono font-semibold",
-                            "{env!(\"CARGO_PKG_VERSION\")}"
-                        }
+                p {
+                    class: "text-gray-600 text-lg leading-relaxed",
+                    "Oops ... You found a hidden page that I use to make"
+                    " sure my website is up to date! Why not check out the "
+                    a {
+                        href: "/res",
+                        class: STYLES.link,
+                        "resource page"
                     }
+                    "!"
+                }
+            }
 
-                    p {
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        "Oops ... You found a hidden page that I use to make"
-                        " sure my website is up to date! Why not check out the "
-                        a {
-                            href: "/res",
-                            class: CSS_LINK_TEXT,
-                            "resource page"
-                        }
-                        "!"
+            // Resource sheet check
+            div {
+                class: "space-y-4",
+                h2 {
+                    class: "text-2xl font-bold text-red-900 mb-4",
+                    "Resource Sheet"
+                }
+                {
+                    match warnings() {
+                        None => rsx! { div { class: "text-gray-400 py-8", "Loading..." } },
+                        Some(rows) if rows.is_empty() => rsx! {
+                            p { class: "text-gray-600", "Every row reads cleanly: expirations, recurrences, categories and audiences are all understood." }
+                        },
+                        Some(rows) => rsx! {
+                            p {
+                                class: "text-gray-600",
+                                "These rows need attention in the sheet (line numbers of its CSV export):"
+                            }
+                            ul {
+                                class: "list-disc pl-6 text-gray-700 space-y-1",
+                                for warning in rows {
+                                    li {
+                                        span { class: "font-mono font-semibold", "Line {warning.line}: " }
+                                        span { class: "font-mono text-red-700", "{warning.problem}" }
+                                        if !warning.caption.is_empty() {
+                                            " ({warning.caption})"
+                                        }
+                                    }
+                                }
+                            }
+                        },
                     }
                 }
             }
@@ -1,200 +1,151 @@
 use dioxus::prelude::*;
-use super::css_preset::*;
-use super::routes::Route;
+use super::theme::STYLES;
+use super::ui::{Callout, CardVariant, ExternalLink, LinkCard, PageShell};
+use super::routes::{is_shown, page_route};
+use super::content;
+use super::markdown::{Markdown, ProseStyle};
+use super::page_news::LatestNews;
 
 const SELFIE_IMG: Asset = asset!("/assets/imgs/selfie.png");
 
+// Pages outside the navigation bar that get a tile: page name, title and blurb
+const WORK_TILES: [(&str, &str, &str); 3] = [
+    ("pub", "Publications", "Explore my research papers and articles."),
+    ("code", "Code", "Browse my open source and research software."),
+    ("talks", "Talks", "Slides and recordings of my presentations."),
+];
+
+const BIO_STYLE: ProseStyle = ProseStyle {
+    paragraph: "text-gray-700 leading-relaxed",
+    strong: "font-semibold",
+    em: "",
+    link: "text-red-600 hover:text-red-800 underline",
+    ..ProseStyle::DEFAULT
+};
+
+const RECRUITING_STYLE: ProseStyle = ProseStyle {
+    paragraph: "text-gray-800 leading-relaxed font-semibold text-center",
+    strong: "font-bold text-red-700",
+    em: "",
+    link: "text-red-700 hover:text-red-900 font-normal",
+    ..ProseStyle::DEFAULT
+};
+
 #[component]
 pub fn Home() -> Element {
+    let content = content::home();
 
     rsx! {
-        div {
-            class: CSS_CONTENT_CONTAINER,
+        PageShell {
+            div {
+                class: "mb-8",
+                h1 {
+                    class: STYLES.page_title,
+                    "{content.name}"
+                }
+                p {
+                    class: "text-gray-600 text-lg",
+                    "{content.tagline}"
+                }
+            }
+
+            div {
+                class: "flex flex-col lg:flex-row gap-8",
                 
                 div {
-                    class: CSS_CONTENT_CARD,
-
-                    div {
-                        class: "mb-8",
-                        h1 {
-                            class: "text-4xl font-bold text-gray-900 mb-2",
-                            "Weiming Hu"
-                        }
-                        p {
-                            class: "text-gray-600 text-lg",
-                            "A practical idealist in open source and open science"
-                        }
+                    class: "lg:w-1/3 flex flex-col items-center justify-center space-y-6 max-w-[25rem] mx-auto",
+                    img {
+                        src: SELFIE_IMG,
+                        alt: "{content.photo_alt}",
+                        class: "w-full h-auto rounded-lg shadow-md"
                     }
 
                     div {
-                        class: "flex flex-col lg:flex-row gap-8",
-                        
+                        class: "mt-1 pt-4 border-t border-gray-200 w-full",
                         div {
-                            class: "lg:w-1/3 flex flex-col items-center justify-center space-y-6 max-w-[25rem] mx-auto",
-                            img {
-                                src: SELFIE_IMG,
-                                alt: "Weiming Hu",
-                                class: "w-full h-auto rounded-lg shadow-md"
+                            class: "text-sm text-gray-600 space-y-1 text-center",
+                            for line in content.address.iter() {
+                                p { "{line}" }
                             }
-
-                            div {
-                                class: "mt-1 pt-4 border-t border-gray-200 w-full",
-                                div {
-                                    class: "text-sm text-gray-600 space-y-1 text-center",
-                                    p { "Geography/Geology Building Room 312"}
-                                    p { "210 Field St, Athens, GA 30602" }
+                            if is_shown("cv") {
+                                Link {
+                                    to: page_route("cv"),
+                                    class: "inline-block mt-2 {STYLES.link}",
+                                    "Curriculum Vitae"
                                 }
                             }
                         }
+                    }
+                }
 
-                        div {
-                            class: "flex-1 space-y-4 w-full",
-                            
-                            p {
-                                class: "text-lg text-gray-800",
-                                "Salute and welcome!"
-                            }
+                div {
+                    class: "flex-1 space-y-4 w-full",
+                    
+                    p {
+                        class: "text-lg text-gray-800",
+                        "{content.greeting}"
+                    }
 
-                            p {
-                                class: "text-gray-700 leading-relaxed",
-                                "I am an "
-                                span { class: "font-semibold", "Assistant Professor" }
-                                " in the "
-                                a { 
-                                    href: "https://geography.uga.edu/directory/people/weiming-hu", 
-                                    target: "_blank",
-                                    class: "text-red-600 hover:text-red-800 underline",
-                                    "Dept. of Geography, Univ. of Georgia"
-                                }
-                                " and a core faculty member of the "
-                                a { 
-                                    href: "https://cgr.uga.edu/bio-weiminghu.html", 
-                                    target: "_blank",
-                                    class: "text-red-600 hover:text-red-800 underline",
-                                    "Center for Geospatial Research"
-                                }
-                                ". My research interests lie primarily in "
-                                span { class: "font-semibold", "machine learning" }
-                                " and "
-                                span { class: "font-semibold", "big spatiotemporal data analytics" }
-                                "."
-                            }
+                    for paragraph in content.bio.iter() {
+                        Markdown { text: paragraph.clone(), style: BIO_STYLE }
+                    }
+                }
 
-                            p {
-                                class: "text-gray-700 leading-relaxed",
-                                "I am particularly passionate about quantifying and understanding the level of "
-                                span { class: "font-semibold", "uncertainty from multi-source data" }
-                                ", e.g., remote sensing, model simulations, and ground observations, and from hybrid dynamical-machine-learning models. My goal is to investigate how to build "
-                                span { class: "font-style: italic", "accurate, reliable, trustworthy" }
-                                ", and "
-                                span { class: "font-style: italic", "realistic" }
-                                " models with machine learning for "
-                                span { class: "font-style: italic", "Environmental and Earth Sciences" }
-                                ". My work has been applied to "
-                                span { class: "font-semibold", "renewable energy forecasting" }
-                                ", "
-                                span { class: "font-semibold", "extreme event forecasting" }
-                                ", and "
-                                span { class: "font-semibold", "water resource management" }
-                                "."
-                            }
-                        }
+            }
 
-                    }
+            div {
+                class: "flex items-center mt-6 justify-center",
+                Callout {
+                    class: "max-w-2xl",
+                    Markdown { text: content.recruiting.clone(), style: RECRUITING_STYLE }
+                }
+            }
 
-                    div {
-                        class: "flex items-center mt-6 justify-center",
-                        div {
-                            class: "bg-red-50 border-l-4 border-red-400 p-4 rounded shadow-sm max-w-2xl",
-                            p {
-                                class: "text-gray-800 leading-relaxed font-semibold text-center",
-                                "I am actively looking for motivated students who are broadly interested in "
-                                span { class: "font-bold text-red-700", "geospatial analysis" }
-                                ", "
-                                span { class: "font-bold text-red-700", "artificial intelligence" }
-                                ", "
-                                span { class: "font-bold text-red-700", "weather" }
-                                ", or "
-                                span { class: "font-bold text-red-700", "climate science" }
-                                ". Please contact me if you are interested in joining the " 
-                                Link { 
-                                    to: Route::Director { pagename: "gaim".to_string() },
-                                    class: "text-red-700 hover:text-red-900 font-normal",
-                                    "Lab for GAIM" 
-                                }
-                                "."
-                            }
-                        }
-                    }
+            div {
+                class: "mt-12",
+                LatestNews {}
+            }
 
-                    // More of my work section
-                    div {
-                        class: "mt-12 flex flex-col items-center",
-                        div {
-                            class: "flex flex-col md:flex-row gap-8 justify-center items-center",
-                            // Publication Card
-                            Link {
-                                to: Route::Director { pagename: "pub".to_string() },
-                                class: "w-64 h-40 bg-white border border-gray-200 rounded-lg shadow flex flex-col items-center justify-center hover:bg-gray-50 hover:shadow-lg hover:border-red-500 transition-all duration-200 cursor-pointer hover:-translate-y-1",
-                                div {
-                                    class: "text-3xl text-red-700 mb-2",
-                                    i { class: "fa-solid fa-book-open" }
-                                }
-                                div {
-                                    class: "font-semibold text-lg text-gray-900 mb-1",
-                                    "Publications"
-                                }
-                                div {
-                                    class: "text-gray-600 text-sm text-center px-4",
-                                    "Explore my research papers and articles."
-                                }
+            // More of my work section
+            div {
+                class: "mt-12 flex flex-col items-center",
+                div {
+                    class: "flex flex-col md:flex-row gap-8 justify-center items-center",
+                    for (name, title, blurb) in WORK_TILES.into_iter().filter(|(name, ..)| is_shown(name)) {
+                        LinkCard {
+                            to: page_route(name),
+                            label: title,
+                            variant: CardVariant::Tile,
+                            div {
+                                class: "text-3xl text-red-700 mb-2",
+                                i { class: content::page(name).map_or("", |p| p.icon.as_str()) }
                             }
-                            // Code Card
-                            Link {
-                                to: Route::Director { pagename: "code".to_string() },
-                                class: "w-64 h-40 bg-white border border-gray-200 rounded-lg shadow flex flex-col items-center justify-center hover:bg-gray-50 hover:shadow-lg hover:border-red-500 transition-all duration-200 cursor-pointer hover:-translate-y-1",
-                                div {
-                                    class: "text-3xl text-red-700 mb-2",
-                                    i { class: "fa-solid fa-code" }
-                                }
-                                div {
-                                    class: "font-semibold text-lg text-gray-900 mb-1",
-                                    "Code"
-                                }
-                                div {
-                                    class: "text-gray-600 text-sm text-center px-4",
-                                    "Browse my open source and research software."
-                                }
+                            div {
+                                class: "font-semibold text-lg text-gray-900 mb-1",
+                                "{title}"
+                            }
+                            div {
+                                class: "text-gray-600 text-sm text-center px-4",
+                                "{blurb}"
                             }
                         }
                     }
+                }
+            }
 
-                    // Social Media Icons
-                    div {
-                        class: "flex justify-center space-x-6 mt-8 pt-6 border-t border-gray-200",
-                        a {
-                            href: "https://geography.uga.edu/directory/people/weiming-hu",
-                            target: "_blank",
-                            class: "text-gray-600 hover:text-red-600 transition-colors text-3xl",
-                            title: "University Profile",
-                            i { class: "fa-solid fa-address-card" }
-                        }
-                        a {
-                            href: "https://github.com/Weiming-Hu",
-                            target: "_blank",
-                            class: "text-gray-600 hover:text-red-600 transition-colors text-3xl",
-                            title: "GitHub",
-                            i { class: "fa-brands fa-github" }
-                        }
-                        a {
-                            href: "https://scholar.google.com/citations?user=2xypOLMAAAAJ&hl",
-                            target: "_blank",
-                            class: "text-gray-600 hover:text-red-600 transition-colors text-3xl",
-                            title: "Google Scholar",
-                            i { class: "fa-solid fa-graduation-cap" }
-                        }
+            // Social Media Icons
+            div {
+                class: "flex justify-center space-x-6 mt-8 pt-6 border-t border-gray-200",
+                for link in content.social.iter() {
+                    ExternalLink {
+                        href: "{link.url}",
+                        class: "text-gray-600 hover:text-red-600 transition-colors text-3xl",
+                        title: "{link.title}",
+                        i { class: "{link.icon}", aria_hidden: "true" }
+                        span { class: "sr-only", "{link.title}" }
                     }
                 }
             }
+        }
     }
 }
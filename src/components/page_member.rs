@@ -0,0 +1,155 @@
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use super::theme::STYLES;
+use super::ui::{ExternalLink, PageShell, SectionHeader};
+use super::content;
+use super::markdown::Markdown;
+use super::page_gaim::{member_photo, MEMBER_STYLE};
+use super::page_pub::{authored_by, fetch_publications, PublicationEntry};
+use super::routes::Route;
+
+#[component]
+pub fn MemberProfile(id: String) -> Element {
+    let mut publications = use_signal(|| None);
+    let member = content::member(&id);
+
+    // Rerun when navigating from one profile to another
+    use_effect(use_reactive!(|id| {
+        let Some(member) = content::member(&id) else { return };
+        spawn_local(async move {
+            let rows = fetch_publications().await.unwrap_or_default();
+            let mut rows = authored_by(&rows, &member.author_names());
+            rows.sort_by(|a, b| b.year.cmp(&a.year));
+            publications.set(Some(rows));
+        });
+    }));
+
+    let Some(member) = member else {
+        return rsx! {
+            PageShell {
+                h1 { class: STYLES.page_title, "Not Found" }
+                p {
+                    class: "text-gray-600 text-lg",
+                    "There is no lab member with this page. Meet everyone on the "
+                    Link {
+                        to: Route::Director { pagename: "gaim".to_string() },
+                        class: STYLES.link,
+                        "Lab page"
+                    }
+                    "."
+                }
+            }
+        };
+    };
+
+    rsx! {
+        PageShell {
+            Link {
+                to: Route::Director { pagename: "gaim".to_string() },
+                class: "text-sm text-gray-500 hover:text-red-700 transition-colors",
+                "← Lab for GAIM"
+            }
+
+            // Header
+            div {
+                class: "mt-4 mb-8 flex flex-col md:flex-row gap-8 items-center",
+                if let Some(photo) = member.photo.as_deref().and_then(member_photo) {
+                    img {
+                        src: photo,
+                        alt: "{member.first_name} {member.last_name}",
+                        class: "aspect-square w-48 h-48 rounded-full object-cover shadow-md"
+                    }
+                }
+                div {
+                    class: "flex-1 text-center md:text-left",
+                    h1 { class: STYLES.page_title, "{member.first_name} {member.last_name}" }
+                    p { class: "text-red-600 font-medium text-lg", "{member.role}" }
+                    if let Some(cohort) = member.cohort {
+                        p { class: "text-sm text-gray-500", "Joined {cohort}" }
+                    }
+                    if let Some(year) = member.graduation {
+                        p { class: "text-sm text-gray-500", "{member.program.degree()}, {year}" }
+                    }
+                    if let Some(placement) = &member.placement {
+                        p { class: "text-sm text-gray-500", "First placement: {placement}" }
+                    }
+                    if let Some(thesis) = &member.thesis {
+                        p {
+                            class: "text-sm text-gray-500",
+                            "Thesis: "
+                            if let Some(url) = &thesis.url {
+                                ExternalLink { href: "{url}", class: "italic {STYLES.link}", "{thesis.title}" }
+                            } else {
+                                i { "{thesis.title}" }
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex justify-center md:justify-start space-x-4 mt-4",
+                        for link in member.links.iter() {
+                            ExternalLink {
+                                href: "{link.url}",
+                                class: "text-gray-600 hover:text-red-600 transition-colors text-2xl",
+                                title: "{link.title}",
+                                i { class: "{link.icon}", aria_hidden: "true" }
+                                span { class: "sr-only", "{link.title}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "space-y-6",
+
+                div {
+                    class: "{STYLES.divider} space-y-3 text-lg",
+                    Markdown { text: member.bio.clone(), style: MEMBER_STYLE }
+                }
+
+                if !member.projects.is_empty() {
+                    div {
+                        class: STYLES.divider,
+                        SectionHeader { title: "Projects" }
+                        ul {
+                            class: "space-y-2",
+                            for project in member.projects.iter() {
+                                li {
+                                    class: "text-gray-700",
+                                    ExternalLink {
+                                        href: "{project.url}",
+                                        class: "font-semibold {STYLES.link}",
+                                        "{project.title}"
+                                    }
+                                    ": {project.summary}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    SectionHeader { title: "Publications" }
+                    match publications() {
+                        None => rsx! { div { class: "text-gray-400 py-8", "Loading ..." } },
+                        Some(rows) if rows.is_empty() => rsx! {
+                            p { class: "text-gray-600", "No publications listed yet." }
+                        },
+                        Some(rows) => rsx! {
+                            for p in rows {
+                                PublicationEntry {
+                                    year: p.year.clone(),
+                                    title: p.title.clone(),
+                                    authors: p.authors.clone(),
+                                    journal: format!("{} ({})", p.journal, p.year),
+                                    website: p.website.clone(),
+                                    note: p.note.clone(),
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
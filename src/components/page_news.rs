@@ -0,0 +1,183 @@
+use dioxus::prelude::*;
+use super::theme::STYLES;
+use super::ui::{LinkCard, PageShell, PillLink, PillTone};
+use super::content::{self, NewsItem};
+use super::markdown::{Markdown, ProseStyle};
+use super::routes::Route;
+
+const CSS_DATE: &str = "text-sm text-gray-500";
+
+const SUMMARY_STYLE: ProseStyle = ProseStyle {
+    paragraph: "text-gray-700 leading-relaxed",
+    ..ProseStyle::CARD
+};
+
+// How many items the Home and Lab pages show
+const LATEST_COUNT: usize = 3;
+
+fn news_date(item: &NewsItem) -> String {
+    item.date.format("%B %-d, %Y").to_string()
+}
+
+#[component]
+fn TagList(tags: Vec<String>, selected: Option<String>) -> Element {
+    rsx! {
+        div {
+            class: "relative z-10 flex flex-wrap gap-2",
+            for tag in tags {
+                PillLink {
+                    to: Route::NewsTagged { tag: tag.clone() },
+                    text: "#{tag}",
+                    tone: if selected.as_ref() == Some(&tag) { PillTone::Strong } else { PillTone::Accent },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NewsCard(id: String, date: String, title: String, summary: String, tags: Vec<String>) -> Element {
+    rsx! {
+        LinkCard {
+            to: Route::NewsPost { id },
+            label: title.clone(),
+
+            p { class: CSS_DATE, "{date}" }
+            h3 {
+                class: "text-lg font-semibold text-gray-900 mb-2 group-hover:text-red-700 transition-colors",
+                "{title}"
+            }
+            Markdown { text: summary, style: SUMMARY_STYLE }
+            if !tags.is_empty() {
+                div { class: "mt-2", TagList { tags } }
+            }
+        }
+    }
+}
+
+#[component]
+fn NewsList(tag: Option<String>) -> Element {
+    let items = content::news().iter().filter(|n| tag.as_ref().is_none_or(|t| n.tags.contains(t)));
+    let tags: Vec<String> = content::news_tags().into_iter().map(String::from).collect();
+
+    rsx! {
+        PageShell {
+            div {
+                class: "mb-8 {STYLES.divider}",
+                h1 {
+                    class: STYLES.page_title,
+                    if let Some(tag) = &tag { "News tagged #{tag}" } else { "News" }
+                }
+                p {
+                    class: "text-gray-600 text-lg mb-4",
+                    "Papers, people, and other updates from the Lab for GAIM. "
+                    if tag.is_some() {
+                        Link {
+                            to: Route::Director { pagename: "news".to_string() },
+                            class: STYLES.link,
+                            "Show all news."
+                        }
+                    }
+                }
+                TagList { tags, selected: tag.clone() }
+            }
+
+            div {
+                class: "space-y-4",
+                for item in items {
+                    NewsCard {
+                        id: item.id.clone(),
+                        date: news_date(item),
+                        title: item.title.clone(),
+                        summary: item.summary().to_string(),
+                        tags: item.tags.clone(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn News() -> Element {
+    rsx! { NewsList { tag: None } }
+}
+
+#[component]
+pub fn NewsTagged(tag: String) -> Element {
+    rsx! { NewsList { tag: Some(tag) } }
+}
+
+#[component]
+pub fn NewsPost(id: String) -> Element {
+    let Some(item) = content::news_item(&id) else {
+        return rsx! {
+            PageShell {
+                h1 { class: STYLES.page_title, "Not Found" }
+                p {
+                    class: "text-gray-600 text-lg",
+                    "There is no news item with this page. See all updates on the "
+                    Link {
+                        to: Route::Director { pagename: "news".to_string() },
+                        class: STYLES.link,
+                        "News page"
+                    }
+                    "."
+                }
+            }
+        };
+    };
+
+    rsx! {
+        PageShell {
+            Link {
+                to: Route::Director { pagename: "news".to_string() },
+                class: "text-sm text-gray-500 hover:text-red-700 transition-colors",
+                "← All news"
+            }
+            div {
+                class: "mt-4 mb-6",
+                p { class: CSS_DATE, "{news_date(item)}" }
+                h1 { class: STYLES.page_title, "{item.title}" }
+                TagList { tags: item.tags.clone() }
+            }
+            div {
+                class: "space-y-4",
+                Markdown { text: item.body.clone() }
+            }
+        }
+    }
+}
+
+// The most recent items, for the Home and Lab pages
+#[component]
+pub(super) fn LatestNews() -> Element {
+    let items = content::news().iter().take(LATEST_COUNT);
+
+    rsx! {
+        div {
+            class: "space-y-4",
+            div {
+                class: "flex items-baseline justify-between",
+                h2 { class: "text-2xl font-bold text-gray-800", "Latest News" }
+                Link {
+                    to: Route::Director { pagename: "news".to_string() },
+                    class: "text-sm text-gray-500 hover:text-red-700 transition-colors",
+                    "All news →"
+                }
+            }
+            div {
+                class: "grid gap-4 md:grid-cols-3",
+                for item in items {
+                    NewsCard {
+                        id: item.id.clone(),
+                        date: news_date(item),
+                        title: item.title.clone(),
+                        summary: item.summary().to_string(),
+                        tags: Vec::new(),
+                    }
+                }
+            }
+        }
+    }
+}
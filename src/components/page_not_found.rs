@@ -0,0 +1,24 @@
+use dioxus::prelude::*;
+use super::theme::STYLES;
+use super::ui::PageShell;
+use super::routes::Route;
+
+// Addresses that match no page, and pages left out of this build
+#[component]
+pub fn PageNotFound(segments: Vec<String>) -> Element {
+    rsx! {
+        PageShell {
+            h1 { class: STYLES.page_title, "Not Found" }
+            p {
+                class: "text-gray-600 text-lg",
+                "There is no page at this address. Start again from the "
+                Link {
+                    to: Route::Home {},
+                    class: STYLES.link,
+                    "Home page"
+                }
+                "."
+            }
+        }
+    }
+}
@@ -1,23 +1,23 @@
-use super::css_preset::*;
+use super::theme::STYLES;
+use super::ui::{ExternalLink, LinkCard, PageShell, SectionHeader};
+use super::markdown::{InlineMarkdown, ProseStyle};
+use super::preload::use_preloaded;
 use csv::ReaderBuilder;
 use wasm_bindgen_futures::spawn_local;
 use dioxus::prelude::*;
 use reqwest::Client;
 
-const CSS_HLINE: &str = "border-b border-gray-200 pb-6";
-const CSS_YEAR: &str = "text-2xl font-bold text-red-900 mb-4";
-
 #[derive(Debug, Clone)]
-struct PubRow {
-    year: String,
-    title: String,
-    authors: String,
-    journal: String,
-    website: String,
-    note: String,
+pub(super) struct PubRow {
+    pub(super) year: String,
+    pub(super) title: String,
+    pub(super) authors: String,
+    pub(super) journal: String,
+    pub(super) website: String,
+    pub(super) note: String,
 }
 
-async fn fetch_publications() -> Option<Vec<PubRow>> {
+pub(super) async fn fetch_publications() -> Option<Vec<PubRow>> {
     let url: &'static str = "https://docs.google.com/spreadsheets/d/1m9TQHNTgvpRE3wg1F-58ovCSFsEr_MwXllsj9sYdViU/export?format=csv";
     let client: Client = Client::new();
     let resp = client.get(url)
@@ -27,27 +27,72 @@ async fn fetch_publications() -> Option<Vec<PubRow>> {
         .text()
         .await
         .ok()?;
+    Some(parse_publications(&resp))
+}
+
+fn parse_publications(csv: &str) -> Vec<PubRow> {
     let mut rdr = ReaderBuilder::new()
         .has_headers(true)
-        .from_reader(resp.as_bytes());
+        .from_reader(csv.as_bytes());
     let mut rows = Vec::new();
-    for result in rdr.records() {
-        if let Ok(record) = result {
-            rows.push(PubRow {
-                year: record.get(0).unwrap_or("").to_string(),
-                title: record.get(1).unwrap_or("").to_string(),
-                authors: record.get(2).unwrap_or("").to_string(),
-                journal: record.get(3).unwrap_or("").to_string(),
-                website: record.get(4).unwrap_or("").to_string(),
-                note: record.get(5).unwrap_or("").to_string(),
-            });
-        }
+    for record in rdr.records().flatten() {
+        rows.push(PubRow {
+            year: record.get(0).unwrap_or("").to_string(),
+            title: record.get(1).unwrap_or("").to_string(),
+            authors: record.get(2).unwrap_or("").to_string(),
+            journal: record.get(3).unwrap_or("").to_string(),
+            website: record.get(4).unwrap_or("").to_string(),
+            note: record.get(5).unwrap_or("").to_string(),
+        });
     }
-    Some(rows)
+    rows
+}
+
+// Letters and digits only, lowercased, so "W. Hu" and "w hu" compare equal
+fn normalize_name(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Publications whose author list mentions any of `names` as whole words
+pub(super) fn authored_by(publications: &[PubRow], names: &[String]) -> Vec<PubRow> {
+    let names: Vec<String> = names.iter().map(|n| normalize_name(n)).filter(|n| !n.is_empty()).collect();
+    publications
+        .iter()
+        .filter(|p| {
+            let authors = format!(" {} ", normalize_name(&p.authors));
+            names.iter().any(|name| authors.contains(&format!(" {} ", name)))
+        })
+        .cloned()
+        .collect()
+}
+
+fn normalize_link(s: &str) -> String {
+    let s = s.trim().to_lowercase();
+    let s = s.trim_start_matches("https://").trim_start_matches("http://");
+    s.trim_end_matches('/').to_string()
+}
+
+// Publications listed by link, or whose title contains one of `keywords`
+pub(super) fn matching(publications: &[PubRow], links: &[String], keywords: &[String]) -> Vec<PubRow> {
+    let links: Vec<String> = links.iter().map(|l| normalize_link(l)).collect();
+    let keywords: Vec<String> = keywords.iter().map(|k| k.to_lowercase()).collect();
+    publications
+        .iter()
+        .filter(|p| {
+            let title = p.title.to_lowercase();
+            links.contains(&normalize_link(&p.website)) || keywords.iter().any(|k| title.contains(k))
+        })
+        .cloned()
+        .collect()
 }
 
 #[component]
-fn PublicationEntry(
+pub(super) fn PublicationEntry(
     year: String,
     title: String,
     authors: String,
@@ -56,13 +101,14 @@ fn PublicationEntry(
     note: String,
 ) -> Element {
     rsx! {
-        a {
-            href: "{website}",
-            target: "_blank",
-            class: "block mb-4 p-4 border-l-4 border-red-400 rounded-r-lg bg-white hover:bg-gray-50 hover:shadow-lg hover:border-red-500 transition-all duration-200 cursor-pointer transform hover:-translate-y-1",
-            
+        LinkCard {
+            to: website,
+            label: title.clone(),
+            new_tab: true,
+            class: "mb-4",
+
             h3 {
-                class: "text-lg font-semibold text-gray-900 mb-2 hover:text-red-700 transition-colors",
+                class: "text-lg font-semibold text-gray-900 mb-2 group-hover:text-red-700 transition-colors",
                 "{title}"
             }
             
@@ -80,7 +126,7 @@ fn PublicationEntry(
                 if !note.is_empty() {
                     span {
                         class: "ml-2 text-xs text-gray-500 italic",
-                        "{note}"
+                        InlineMarkdown { text: note, style: ProseStyle::CARD }
                     }
                 }
             }
@@ -90,7 +136,8 @@ fn PublicationEntry(
 
 #[component]
 pub fn Pub() -> Element {
-    let mut publications = use_signal(|| Vec::new());
+    let preloaded = use_preloaded();
+    let mut publications = use_signal(|| preloaded.publications.as_deref().map(parse_publications).unwrap_or_default());
 
     use_effect({
         move || {
@@ -103,64 +150,59 @@ pub fn Pub() -> Element {
     });
 
     rsx! {
-        div {
-            class: CSS_CONTENT_CONTAINER,
+        PageShell {
+            // Header
             div {
-                class: CSS_CONTENT_CARD,
-                // Header
-                div {
-                    class: "mb-8",
-                    h1 {
-                        class: CSS_PAGE_TITLE,
-                        "Publications"
-                    }
-                    p {
-                        class: "text-gray-600 text-lg",
-                        "Selected research publications and contributions below. "
-                        a { 
-                            href: "https://scholar.google.com/citations?user=2xypOLMAAAAJ&hl",
-                            target: "_blank",
-                            class: "mb-2 hover:text-red-700 transition-colors",
-                            "My full profile is on Google Scholar."
-                        }
+                class: "mb-8",
+                h1 {
+                    class: STYLES.page_title,
+                    "Publications"
+                }
+                p {
+                    class: "text-gray-600 text-lg",
+                    "Selected research publications and contributions below. "
+                    ExternalLink { 
+                        href: "https://scholar.google.com/citations?user=2xypOLMAAAAJ&hl",
+                        class: "mb-2 hover:text-red-700 transition-colors",
+                        "My full profile is on Google Scholar."
                     }
                 }
+            }
 
-                // Publications List
-                div {
-                    class: "space-y-6",
-                    {
-                        if publications().is_empty() {
-                            rsx!(div { class: "text-gray-400 py-8", "Loading ..." })
-                        } else {
-                            let mut years: Vec<String> = publications().iter().map(|p| p.year.clone()).collect();
-                            years.sort_by(|a, b| b.cmp(a));
-                            years.dedup();
-                            rsx! {{
-                                years.into_iter().map(|year| {
-                                    rsx! {
-                                        div {  
-                                            class: CSS_HLINE,
-                                            h2 { class: CSS_YEAR, "{year}" }
-                                            {
-                                                publications().iter().filter(|p| p.year == year).map(|p| {
-                                                    rsx! {
-                                                        PublicationEntry {
-                                                            year: p.year.clone(),
-                                                            title: p.title.clone(),
-                                                            authors: p.authors.clone(),
-                                                            journal: p.journal.clone(),
-                                                            website: p.website.clone(),
-                                                            note: p.note.clone(),
-                                                        }
+            // Publications List
+            div {
+                class: "space-y-6",
+                {
+                    if publications().is_empty() {
+                        rsx!(div { class: "text-gray-400 py-8", "Loading ..." })
+                    } else {
+                        let mut years: Vec<String> = publications().iter().map(|p| p.year.clone()).collect();
+                        years.sort_by(|a, b| b.cmp(a));
+                        years.dedup();
+                        rsx! {{
+                            years.into_iter().map(|year| {
+                                rsx! {
+                                    div {  
+                                        class: STYLES.divider,
+                                        SectionHeader { title: "{year}" }
+                                        {
+                                            publications().iter().filter(|p| p.year == year).map(|p| {
+                                                rsx! {
+                                                    PublicationEntry {
+                                                        year: p.year.clone(),
+                                                        title: p.title.clone(),
+                                                        authors: p.authors.clone(),
+                                                        journal: p.journal.clone(),
+                                                        website: p.website.clone(),
+                                                        note: p.note.clone(),
                                                     }
-                                                })
-                                            }
+                                                }
+                                            })
                                         }
                                     }
-                                })
-                            }}
-                        }
+                                }
+                            })
+                        }}
                     }
                 }
             }
@@ -1,34 +1,20 @@
 use dioxus::prelude::*;
-use super::css_preset::*;
-use chrono::{NaiveDate, Utc, Duration};
+use super::theme::STYLES;
+use super::ui::{CardVariant, ExternalLink, LinkCard, PageShell, Pill, PillTone};
+use super::routes::Route;
+use super::markdown::{plain_text, InlineMarkdown, ProseStyle};
+use super::preload::use_preloaded;
+use super::timezone::{self, deadline, use_visitor_tz, with_lab_time, LAB_TZ};
+use chrono::Duration;
+use chrono_tz::Tz;
 use wasm_bindgen_futures::spawn_local;
 use reqwest::Client;
-use csv::ReaderBuilder;
 
-#[derive(Debug, Clone)]
-struct ResourceRow {
-    caption: String,
-    link: String,
-    expiration_date: Option<NaiveDate>,
-    keywords: Vec<String>,
-}
-
-fn parse_keywords(s: &str) -> Vec<String> {
-    s.split(',')
-    .map(|kw| kw.trim().to_string())
-    .filter(|kw| !kw.is_empty())
-    .collect()
-}
+pub mod sheet;
 
-fn parse_expiration(s: &str) -> Option<NaiveDate> {
-    if s.trim().is_empty() {
-        None
-    } else {
-        NaiveDate::parse_from_str(s.trim(), "%Y/%m/%d").ok()
-    }
-}
+use sheet::{parse_sheet, Audience, Expiration, Recurrence, ResourceRow, Sheet};
 
-async fn fetch_resources() -> Option<Vec<ResourceRow>> {
+pub(super) async fn fetch_resources() -> Option<Sheet> {
     let url = "https://docs.google.com/spreadsheets/d/1y-_hrRYhylnryjiOS1f4SNu_NnMP5j6231Qb8qG-0Zk/export?format=csv";
     let client = Client::new();
     let resp = client.get(url)
@@ -38,41 +24,34 @@ async fn fetch_resources() -> Option<Vec<ResourceRow>> {
         .text()
         .await
         .ok()?;
-    let mut rdr = ReaderBuilder::new()
-        .has_headers(true)
-        .from_reader(resp.as_bytes());
-    let mut rows = Vec::new();
-    for result in rdr.records() {
-        if let Ok(record) = result {
-            let caption = record.get(0).unwrap_or("").to_string();
-            let expiration_date = parse_expiration(record.get(1).unwrap_or(""));
-            let keywords = parse_keywords(record.get(2).unwrap_or(""));
-            let link = record.get(3).unwrap_or("").to_string();
-            rows.push(ResourceRow { caption, link, expiration_date, keywords });
-        }
-    }
-    Some(rows)
+    Some(parse_sheet(&resp))
 }
 
 fn filter_and_sort_resources(resources: Vec<ResourceRow>, days: i64) -> Vec<ResourceRow> {
-    let today = Utc::now().date_naive();
+    // A deadline is listed until the day ends in the lab
+    let today = timezone::today(LAB_TZ);
     let cutoff = today + Duration::days(days);
+    let resources: Vec<ResourceRow> = resources
+        .into_iter()
+        .map(|r| r.with_next_deadline(today))
+        .collect();
     let mut expiring: Vec<ResourceRow> = resources
         .iter()
         .filter(|r| {
-            match r.expiration_date {
+            match r.expiration.date() {
                 Some(date) => date >= today && date <= cutoff,
                 None => false,
             }
         })
         .cloned()
         .collect();
+    // Rolling, unknown and unparseable deadlines are kept so visitors can check the posting
     let mut non_expiring: Vec<ResourceRow> = resources
         .iter()
-        .filter(|r| r.expiration_date.is_none())
+        .filter(|r| r.expiration.date().is_none())
         .cloned()
         .collect();
-    expiring.sort_by(|a, b| a.expiration_date.cmp(&b.expiration_date));
+    expiring.sort_by_key(|r| r.expiration.date());
     non_expiring.sort_by(|a, b| a.caption.cmp(&b.caption));
     expiring.extend(non_expiring);
     expiring
@@ -82,22 +61,26 @@ fn filter_and_sort_resources(resources: Vec<ResourceRow>, days: i64) -> Vec<Reso
 fn ResourceCard(
     caption: String,
     link: String,
-    expiration_date: Option<NaiveDate>,
+    expiration: Expiration,
+    recurring: bool,
     keywords: Vec<String>,
+    category: Option<&'static str>,
+    visitor: Tz,
 ) -> Element {
     rsx! {
-        a {
-            href: "{link}",
-            target: "_blank",
-            class: "block p-6 border border-gray-200 rounded-lg bg-white hover:bg-gray-50 hover:shadow-lg hover:border-red-300 transition-all duration-200 cursor-pointer transform hover:-translate-y-1",
+        LinkCard {
+            to: link,
+            label: plain_text(&caption),
+            new_tab: true,
+            variant: CardVariant::Outline,
             div {
                 class: "flex flex-col md:flex-row items-center justify-between w-full",
                 // Caption top/left
                 div {
                     class: "w-full md:w-4/5 pr-2 text-center md:text-left mb-2",
                     h3 {
-                        class: "text-lg leading-relaxed text-gray-900 hover:text-red-700 transition-colors",
-                        "{caption}"
+                        class: "text-lg leading-relaxed text-gray-900 group-hover:text-red-700 transition-colors",
+                        InlineMarkdown { text: caption.clone(), style: ProseStyle::CARD }
                     }
                 }
                 // Keywords and expiration below/right
@@ -105,18 +88,32 @@ fn ResourceCard(
                     class: "w-full md:w-1/5 flex flex-col items-center md:items-end gap-2 mt-2",
                     div {
                         class: "flex flex-wrap gap-2 justify-center md:justify-end",
+                        if let Some(category) = category {
+                            Pill { text: category, tone: PillTone::Strong }
+                        }
                         {
                             keywords.iter().map(|kw| rsx! {
-                                span { class: "inline-flex items-center px-2 py-1 text-xs font-medium bg-red-100 text-gray-500 rounded-full", "{kw}" }
+                                Pill { text: kw.clone() }
                             })
                         }
                     }
                     div {
                         class: "mt-2 text-center md:text-right",
-                        if let Some(date) = expiration_date {
-                            div { class: "text-xs text-gray-500", "Expires: {date}" }
-                        } else {
-                            div { class: "text-xs text-gray-500", "Never Expires" }
+                        div {
+                            class: "text-xs text-gray-500",
+                            match expiration {
+                                Expiration::Date(date) if recurring => format!("Next deadline: {} (recurring annually)", date),
+                                Expiration::Date(date) => format!("Expires: {}", date),
+                                Expiration::Never => "Never Expires".to_string(),
+                                Expiration::Rolling => "Rolling Deadline".to_string(),
+                                Expiration::Unknown | Expiration::Invalid(_) => "Check Posting for Deadline".to_string(),
+                            }
+                        }
+                        if let Expiration::Date(date) = expiration {
+                            div {
+                                class: "text-xs text-gray-400",
+                                {format!("Closes {}", with_lab_time(deadline(date), visitor, "%b %-d, %-I:%M %p"))}
+                            }
                         }
                     }
                 }
@@ -125,17 +122,73 @@ fn ResourceCard(
     }
 }
 
+const CSS_AUDIENCE_TAB: &str = "px-4 py-2 rounded-full border border-gray-200 text-gray-600 hover:border-red-300 hover:text-red-700 transition-colors";
+const CSS_AUDIENCE_TAB_SELECTED: &str = "px-4 py-2 rounded-full border border-red-400 bg-red-50 text-red-700 font-semibold";
+
 #[component]
 pub fn Resources() -> Element {
-    let mut resources = use_signal(|| Vec::new());
+    rsx!(ResourceBoard { audience: None })
+}
+
+#[component]
+pub fn ResourcesFor(audience: String) -> Element {
+    let Some(audience) = Audience::from_slug(&audience) else {
+        return rsx! {
+            PageShell {
+                h1 { class: STYLES.page_title, "Not Found" }
+                p {
+                    class: "text-gray-600 text-lg",
+                    "There is no resource list for this audience. See all resources on the "
+                    Link {
+                        to: Route::Director { pagename: "res".to_string() },
+                        class: STYLES.link,
+                        "Resources page"
+                    }
+                    "."
+                }
+            }
+        };
+    };
+    rsx!(ResourceBoard { audience: Some(audience) })
+}
+
+#[component]
+fn AudienceTabs(selected: Option<Audience>) -> Element {
+    rsx! {
+        nav {
+            class: "flex flex-wrap gap-2 justify-center md:justify-start",
+            Link {
+                to: Route::Director { pagename: "res".to_string() },
+                class: if selected.is_none() { CSS_AUDIENCE_TAB_SELECTED } else { CSS_AUDIENCE_TAB },
+                "Everyone"
+            }
+            for audience in Audience::ALL {
+                Link {
+                    to: Route::ResourcesFor { audience: audience.slug().to_string() },
+                    class: if selected == Some(audience) { CSS_AUDIENCE_TAB_SELECTED } else { CSS_AUDIENCE_TAB },
+                    "{audience.label()}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ResourceBoard(audience: Option<Audience>) -> Element {
     let days_to_expire = 180;
+    let preloaded = use_preloaded();
+    let mut resources = use_signal(|| {
+        let rows = preloaded.resources.as_deref().map(parse_sheet).unwrap_or_default().rows;
+        filter_and_sort_resources(rows, days_to_expire)
+    });
+    let visitor = use_visitor_tz();
 
     // Fetch and parse resources on mount
     use_effect({
         move || {
             spawn_local(async move {
-                if let Some(rows) = fetch_resources().await {
-                    let filtered = filter_and_sort_resources(rows, days_to_expire);
+                if let Some(sheet) = fetch_resources().await {
+                    let filtered = filter_and_sort_resources(sheet.rows, days_to_expire);
                     resources.set(filtered);
                 }
             });
@@ -143,59 +196,60 @@ pub fn Resources() -> Element {
     });
 
     rsx! {
-        div {
-            class: format!("{} relative z-10", CSS_CONTENT_CONTAINER),
+        PageShell {
             div {
-                class: CSS_CONTENT_CARD,
-                div {
-                    class: "mb-12 space-y-6",
-                    h1 {
-                        class: CSS_PAGE_TITLE,
-                        "Resources"
-                    }
-                    p {
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        "I keep this page with resources that hopefully can help with "
-                        span { class: "font-semibold text-red-700", "your study, research, and career" }
-                        {format!(". I'm only showing items that do not expire or expire in {} days.", days_to_expire)}
-                        " You can check out the "
-                        a {
-                            href: "https://docs.google.com/spreadsheets/d/1y-_hrRYhylnryjiOS1f4SNu_NnMP5j6231Qb8qG-0Zk/edit?usp=sharing",
-                            target: "_blank",
-                            class: CSS_LINK_TEXT,
-                            "full list"
-                        }
-                        " for other items that might be helpful!"
-                    }
-                    p {
-                        class: "text-gray-600 text-lg leading-relaxed",
-                        "Obviously, you need reach out to the posting agency if you have questions regarding the program. "
-                        "But I'm always open to discussing ideas and strategies. Good luck!"
+                class: "mb-12 space-y-6",
+                h1 {
+                    class: STYLES.page_title,
+                    "Resources"
+                }
+                p {
+                    class: "text-gray-600 text-lg leading-relaxed",
+                    "I keep this page with resources that hopefully can help with "
+                    span { class: "font-semibold text-red-700", "your study, research, and career" }
+                    {format!(". I'm only showing items that do not expire or expire in {} days.", days_to_expire)}
+                    " You can check out the "
+                    ExternalLink {
+                        href: "https://docs.google.com/spreadsheets/d/1y-_hrRYhylnryjiOS1f4SNu_NnMP5j6231Qb8qG-0Zk/edit?usp=sharing",
+                        class: STYLES.link,
+                        "full list"
                     }
+                    " for other items that might be helpful!"
                 }
+                p {
+                    class: "text-gray-600 text-lg leading-relaxed",
+                    "Obviously, you need reach out to the posting agency if you have questions regarding the program. "
+                    "But I'm always open to discussing ideas and strategies. Good luck!"
+                }
+                AudienceTabs { selected: audience }
+            }
 
-                {
-                    if resources.is_empty() {
-                        rsx! { div { class: "text-gray-400 py-8", "Loading..." } }
-                    } else {
-                        rsx!(
-                            div {
-                                class: "w-full flex flex-col gap-6 mt-8",
-                                {
-                                    resources().iter().map(|row| {
-                                        rsx! {
-                                            ResourceCard {
-                                                caption: row.caption.clone(),
-                                                link: row.link.clone(),
-                                                expiration_date: row.expiration_date,
-                                                keywords: row.keywords.clone(),
-                                            }
+            {
+                if resources.is_empty() {
+                    rsx! { div { class: "text-gray-400 py-8", "Loading..." } }
+                } else if !resources().iter().any(|row| row.is_for(audience)) {
+                    rsx! { div { class: "text-gray-400 py-8", "Nothing here right now. Check back soon!" } }
+                } else {
+                    rsx!(
+                        div {
+                            class: "w-full flex flex-col gap-6 mt-8",
+                            {
+                                resources().iter().filter(|row| row.is_for(audience)).map(|row| {
+                                    rsx! {
+                                        ResourceCard {
+                                            caption: row.caption.clone(),
+                                            link: row.link.clone(),
+                                            expiration: row.expiration.clone(),
+                                            recurring: matches!(row.recurrence, Some(Recurrence::Yearly | Recurrence::OnDay { .. })),
+                                            keywords: row.keywords.clone(),
+                                            category: row.category.label(),
+                                            visitor: visitor(),
                                         }
-                                    })
-                                }
+                                    }
+                                })
                             }
-                        )
-                    }
+                        }
+                    )
                 }
             }
         }
@@ -0,0 +1,332 @@
+use chrono::{Datelike, NaiveDate};
+use csv::ReaderBuilder;
+
+// The resources spreadsheet: one row per posting, read from its CSV export. Cells are
+// parsed leniently and anything unreadable is kept so it can be reported on the Info page.
+
+// Formats seen in the sheet so far. Month names are matched case-insensitively by chrono.
+const EXPIRATION_FORMATS: [&str; 8] = [
+    "%Y/%m/%d",
+    "%Y-%m-%d",
+    "%m/%d/%Y",
+    "%Y.%m.%d",
+    "%b %d, %Y",
+    "%B %d, %Y",
+    "%d %b %Y",
+    "%d %B %Y",
+];
+
+const EXPIRATION_ROLLING: [&str; 4] = ["rolling", "ongoing", "open until filled", "continuous"];
+const EXPIRATION_UNKNOWN: [&str; 4] = ["tbd", "tba", "unknown", "varies"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expiration {
+    Date(NaiveDate),
+    Never,
+    Rolling,
+    Unknown,
+    // The cell has a value that none of the known formats understand.
+    Invalid(String),
+}
+
+impl Expiration {
+    pub fn date(&self) -> Option<NaiveDate> {
+        match self {
+            Expiration::Date(date) => Some(*date),
+            _ => None,
+        }
+    }
+}
+
+// Month-day formats for the recurrence column, parsed against a leap year so Feb 29 is accepted.
+const RECURRENCE_FORMATS: [&str; 4] = ["%m/%d", "%m-%d", "%b %d", "%B %d"];
+const RECURRENCE_YEARLY: [&str; 4] = ["yearly", "annually", "annual", "every year"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Recurrence {
+    // Repeats on the month and day of the expiration date
+    Yearly,
+    OnDay { month: u32, day: u32 },
+    Invalid(String),
+}
+
+impl Recurrence {
+    // First occurrence on or after `today`, anchored on the expiration date for `Yearly`.
+    pub fn next_occurrence(&self, expiration: &Expiration, today: NaiveDate) -> Option<NaiveDate> {
+        let (month, day) = match self {
+            Recurrence::Yearly => {
+                let date = expiration.date()?;
+                if date >= today {
+                    return Some(date);
+                }
+                (date.month(), date.day())
+            }
+            Recurrence::OnDay { month, day } => (*month, *day),
+            Recurrence::Invalid(_) => return expiration.date(),
+        };
+        [today.year(), today.year() + 1]
+            .into_iter()
+            .filter_map(|year| {
+                // Feb 29 falls back to Feb 28 outside leap years
+                NaiveDate::from_ymd_opt(year, month, day)
+                    .or_else(|| NaiveDate::from_ymd_opt(year, month, day - 1))
+            })
+            .find(|date| *date >= today)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Category {
+    Fellowship,
+    Internship,
+    Job,
+    Funding,
+    Program,
+    Other,
+    Invalid(String),
+}
+
+impl Category {
+    pub fn parse(s: &str) -> Self {
+        match s.trim().to_lowercase().as_str() {
+            "fellowship" | "scholarship" => Category::Fellowship,
+            "internship" => Category::Internship,
+            "job" | "position" | "job posting" => Category::Job,
+            "funding" | "grant" => Category::Funding,
+            "program" | "workshop" | "summer school" => Category::Program,
+            "" | "other" => Category::Other,
+            _ => Category::Invalid(s.trim().to_string()),
+        }
+    }
+
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            Category::Fellowship => Some("Fellowship"),
+            Category::Internship => Some("Internship"),
+            Category::Job => Some("Job"),
+            Category::Funding => Some("Funding"),
+            Category::Program => Some("Program"),
+            Category::Other | Category::Invalid(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Audience {
+    Undergrad,
+    Grad,
+    Postdoc,
+    Faculty,
+}
+
+impl Audience {
+    pub const ALL: [Audience; 4] = [Audience::Undergrad, Audience::Grad, Audience::Postdoc, Audience::Faculty];
+
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Audience::Undergrad => "undergrad",
+            Audience::Grad => "grad",
+            Audience::Postdoc => "postdoc",
+            Audience::Faculty => "faculty",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Audience::Undergrad => "Undergraduates",
+            Audience::Grad => "Graduate Students",
+            Audience::Postdoc => "Postdocs",
+            Audience::Faculty => "Faculty",
+        }
+    }
+
+    pub fn from_slug(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "undergrad" | "undergraduate" => Some(Audience::Undergrad),
+            "grad" | "graduate" | "phd" | "ms" | "masters" => Some(Audience::Grad),
+            "postdoc" => Some(Audience::Postdoc),
+            "faculty" => Some(Audience::Faculty),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResourceRow {
+    // Where the row starts in the CSV export
+    pub line: usize,
+    pub caption: String,
+    pub link: String,
+    pub expiration: Expiration,
+    pub recurrence: Option<Recurrence>,
+    pub keywords: Vec<String>,
+    pub category: Category,
+    // Empty means the item is relevant to everyone
+    pub audiences: Vec<Audience>,
+    pub unknown_audiences: Vec<String>,
+}
+
+impl ResourceRow {
+    pub fn is_for(&self, audience: Option<Audience>) -> bool {
+        match audience {
+            Some(audience) => self.audiences.is_empty() || self.audiences.contains(&audience),
+            None => true,
+        }
+    }
+}
+
+impl ResourceRow {
+    // Moves a recurring deadline to its next occurrence so expired annual items stay listed.
+    pub fn with_next_deadline(mut self, today: NaiveDate) -> Self {
+        if let Some(recurrence) = &self.recurrence {
+            if let Some(date) = recurrence.next_occurrence(&self.expiration, today) {
+                self.expiration = Expiration::Date(date);
+            }
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SheetWarning {
+    pub line: usize,
+    pub caption: String,
+    pub problem: String,
+}
+
+pub fn parse_keywords(s: &str) -> Vec<String> {
+    s.split(',')
+    .map(|kw| kw.trim().to_string())
+    .filter(|kw| !kw.is_empty())
+    .collect()
+}
+
+// Returns the audiences found in the cell and the entries that are not recognized.
+pub fn parse_audiences(s: &str) -> (Vec<Audience>, Vec<String>) {
+    let mut audiences = Vec::new();
+    let mut unknown = Vec::new();
+    for entry in parse_keywords(s) {
+        match Audience::from_slug(&entry) {
+            Some(audience) if !audiences.contains(&audience) => audiences.push(audience),
+            Some(_) => {}
+            None => unknown.push(entry),
+        }
+    }
+    (audiences, unknown)
+}
+
+pub fn parse_expiration(s: &str) -> Expiration {
+    let s = s.trim();
+    let lower = s.to_lowercase();
+    if s.is_empty() || lower == "never" {
+        return Expiration::Never;
+    }
+    if EXPIRATION_ROLLING.contains(&lower.as_str()) {
+        return Expiration::Rolling;
+    }
+    if EXPIRATION_UNKNOWN.contains(&lower.as_str()) {
+        return Expiration::Unknown;
+    }
+    EXPIRATION_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(s, fmt).ok())
+        .map(Expiration::Date)
+        .unwrap_or_else(|| Expiration::Invalid(s.to_string()))
+}
+
+pub fn parse_recurrence(s: &str) -> Option<Recurrence> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    if RECURRENCE_YEARLY.contains(&s.to_lowercase().as_str()) {
+        return Some(Recurrence::Yearly);
+    }
+    let recurrence = RECURRENCE_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(&format!("{} 2000", s), &format!("{} %Y", fmt)).ok())
+        .map(|date| Recurrence::OnDay { month: date.month(), day: date.day() })
+        .unwrap_or_else(|| Recurrence::Invalid(s.to_string()));
+    Some(recurrence)
+}
+
+// The readable rows of the sheet, and a warning for each record the CSV reader gave up on
+#[derive(Debug, Clone, Default)]
+pub struct Sheet {
+    pub rows: Vec<ResourceRow>,
+    pub unreadable: Vec<SheetWarning>,
+}
+
+// Records and cells that could not be understood, in the order of the CSV export.
+pub fn sheet_warnings(sheet: &Sheet) -> Vec<SheetWarning> {
+    let mut warnings = sheet.unreadable.clone();
+    for row in &sheet.rows {
+        let mut problems = Vec::new();
+        if let Expiration::Invalid(raw) = &row.expiration {
+            problems.push(format!("unreadable expiration \"{}\"", raw));
+        }
+        match &row.recurrence {
+            Some(Recurrence::Invalid(raw)) => problems.push(format!("unreadable recurrence \"{}\"", raw)),
+            Some(Recurrence::Yearly) if row.expiration.date().is_none() => {
+                problems.push("recurs yearly but has no expiration date".to_string())
+            }
+            _ => {}
+        }
+        if let Category::Invalid(raw) = &row.category {
+            problems.push(format!("unknown category \"{}\"", raw));
+        }
+        for raw in &row.unknown_audiences {
+            problems.push(format!("unknown audience \"{}\"", raw));
+        }
+        for problem in problems {
+            warnings.push(SheetWarning { line: row.line, caption: row.caption.clone(), problem });
+        }
+    }
+    warnings.sort_by_key(|w| w.line);
+    warnings
+}
+
+// Rows of the sheet's CSV export, header excluded
+pub fn parse_sheet(csv: &str) -> Sheet {
+    let mut rdr = ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(csv.as_bytes());
+    let mut sheet = Sheet::default();
+    for result in rdr.records() {
+        let record = match result {
+            Ok(record) => record,
+            Err(err) => {
+                let problem = match err.kind() {
+                    csv::ErrorKind::UnequalLengths { expected_len, len, .. } => {
+                        format!("has {} cells where the header has {}", len, expected_len)
+                    }
+                    _ => format!("could not be read ({})", err),
+                };
+                let line = err.position().map_or(0, |p| p.line() as usize);
+                sheet.unreadable.push(SheetWarning { line, caption: String::new(), problem });
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, |p| p.line() as usize);
+        let caption = record.get(0).unwrap_or("").to_string();
+        let expiration = parse_expiration(record.get(1).unwrap_or(""));
+        let keywords = parse_keywords(record.get(2).unwrap_or(""));
+        let link = record.get(3).unwrap_or("").to_string();
+        // Optional columns, older copies of the sheet do not have them
+        let recurrence = parse_recurrence(record.get(4).unwrap_or(""));
+        let category = Category::parse(record.get(5).unwrap_or(""));
+        let (audiences, unknown_audiences) = parse_audiences(record.get(6).unwrap_or(""));
+        sheet.rows.push(ResourceRow {
+            line,
+            caption,
+            link,
+            expiration,
+            recurrence,
+            keywords,
+            category,
+            audiences,
+            unknown_audiences,
+        });
+    }
+    sheet
+}
@@ -0,0 +1,163 @@
+use dioxus::prelude::*;
+use chrono::Datelike;
+use super::theme::STYLES;
+use super::timezone::{self, LAB_TZ};
+use super::ui::{Callout, Card, ExternalLink, PageShell, SectionHeader};
+use super::content::{self, Talk};
+
+// PDFs named by `slides` in assets/content/talks.toml
+const SLIDES_DIR: Asset = asset!("/assets/slides");
+
+// Where a video is hosted, and the player that plays it
+#[derive(Debug, Clone, PartialEq)]
+struct VideoEmbed {
+    host: &'static str,
+    player: String,
+}
+
+// Video id up to the first query, fragment or path separator
+fn video_id(s: &str) -> Option<&str> {
+    s.split(['?', '&', '/', '#']).next().filter(|id| !id.is_empty())
+}
+
+// Players that do not set tracking cookies, or ask not to track, for YouTube and Vimeo links
+fn video_embed(url: &str) -> Option<VideoEmbed> {
+    let rest = url.trim().trim_start_matches("https://").trim_start_matches("http://").trim_start_matches("www.");
+    let youtube = |id: &str| VideoEmbed {
+        host: "YouTube",
+        player: format!("https://www.youtube-nocookie.com/embed/{}?autoplay=1", id),
+    };
+    let vimeo = |id: &str| VideoEmbed {
+        host: "Vimeo",
+        player: format!("https://player.vimeo.com/video/{}?dnt=1&autoplay=1", id),
+    };
+
+    if let Some(query) = rest.strip_prefix("youtube.com/watch?") {
+        query.split('&').find_map(|p| p.strip_prefix("v=")).and_then(video_id).map(youtube)
+    } else if let Some(path) = rest.strip_prefix("youtu.be/") {
+        video_id(path).map(youtube)
+    } else if let Some(path) = rest.strip_prefix("vimeo.com/") {
+        video_id(path).map(vimeo)
+    } else {
+        None
+    }
+}
+
+// Nothing is requested from the video host until the visitor asks for it
+#[component]
+fn Video(title: String, url: String) -> Element {
+    let mut loaded = use_signal(|| false);
+
+    let Some(embed) = video_embed(&url) else {
+        return rsx! {
+            ExternalLink { href: "{url}", class: STYLES.link, "Video" }
+        };
+    };
+
+    rsx! {
+        div {
+            class: "w-full aspect-video rounded-lg overflow-hidden bg-gray-900",
+            if loaded() {
+                iframe {
+                    src: "{embed.player}",
+                    title: "{title}",
+                    class: "w-full h-full",
+                    "loading": "lazy",
+                    allow: "autoplay; fullscreen; picture-in-picture",
+                    allowfullscreen: true,
+                }
+            } else {
+                div {
+                    class: "w-full h-full flex flex-col items-center justify-center gap-2 text-white",
+                    button {
+                        r#type: "button",
+                        class: "flex flex-col items-center gap-2 p-4 rounded-lg hover:bg-gray-800 transition-colors cursor-pointer",
+                        onclick: move |_| loaded.set(true),
+                        i { class: "fa-solid fa-circle-play text-5xl" }
+                        span { class: "font-medium", "Play video" }
+                    }
+                    p {
+                        class: "text-xs text-gray-300 px-4 text-center",
+                        "Loads the player from {embed.host}, which may set cookies. "
+                        ExternalLink { href: "{url}", class: "underline", "Open on {embed.host} instead" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TalkEntry(talk: Talk) -> Element {
+    rsx! {
+        Card {
+            class: "mb-4 space-y-2",
+            p { class: "text-sm text-gray-500", "{talk.date.format(\"%B %-d, %Y\")} · {talk.location}" }
+            h3 { class: "text-lg font-semibold text-gray-900", "{talk.title}" }
+            p { class: "font-medium text-red-700 italic", "{talk.event}" }
+            if let Some(slides) = &talk.slides {
+                ExternalLink {
+                    href: "{SLIDES_DIR}/{slides}",
+                    class: "inline-block text-sm font-medium {STYLES.link}",
+                    i { class: "fa-solid fa-file-pdf mr-1" }
+                    "Slides"
+                }
+            }
+            if let Some(video) = &talk.video {
+                div {
+                    class: "max-w-2xl",
+                    Video { title: talk.title.clone(), url: video.clone() }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Talks() -> Element {
+    let today = timezone::today(LAB_TZ);
+    // Soonest first
+    let upcoming: Vec<&Talk> = content::talks().iter().rev().filter(|t| t.date >= today).collect();
+    let past: Vec<&Talk> = content::talks().iter().filter(|t| t.date < today).collect();
+    let mut years: Vec<i32> = past.iter().map(|t| t.date.year()).collect();
+    years.dedup();
+
+    rsx! {
+        PageShell {
+            div {
+                class: "mb-8",
+                h1 { class: STYLES.page_title, "Talks" }
+                p {
+                    class: "text-gray-600 text-lg",
+                    "Invited talks and conference presentations, with slides and recordings when available."
+                }
+            }
+
+            if !upcoming.is_empty() {
+                Callout {
+                    class: "mb-8",
+                    SectionHeader { title: "Upcoming" }
+                    for talk in upcoming {
+                        TalkEntry { talk: talk.clone() }
+                    }
+                }
+            }
+
+            div {
+                class: "space-y-6",
+                if years.is_empty() {
+                    p { class: "text-gray-600", "No talks listed yet." }
+                }
+                for year in years {
+                    div {
+                        class: STYLES.divider,
+                        SectionHeader { title: "{year}" }
+                        for talk in past.iter().filter(|t| t.date.year() == year) {
+                            TalkEntry { talk: (*talk).clone() }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use dioxus::prelude::*;
+use super::theme::STYLES;
+use super::timezone::{self, LAB_TZ};
+use super::ui::{Card, ExternalLink, PageShell};
+use super::content::{self, Course};
+use super::markdown::{Markdown, ProseStyle};
+
+const CSS_GROUP: &str = "text-2xl font-bold text-red-900 mb-4";
+
+const DESCRIPTION_STYLE: ProseStyle = ProseStyle {
+    paragraph: "text-gray-700 leading-relaxed",
+    ..ProseStyle::DEFAULT
+};
+
+#[component]
+fn CourseCard(course: Course) -> Element {
+    let mut terms = course.terms.clone();
+    terms.sort_by(|a, b| b.cmp(a));
+    let terms = terms.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", ");
+
+    rsx! {
+        Card {
+            class: "mb-4 space-y-2",
+            h3 {
+                class: "text-lg font-semibold text-gray-900",
+                span { class: "text-red-700 mr-2", "{course.code}" }
+                "{course.title}"
+            }
+            p { class: "text-sm text-gray-500", "{terms}" }
+            Markdown { text: course.description.clone(), style: DESCRIPTION_STYLE }
+            if course.syllabus.is_some() || !course.materials.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-4 text-sm",
+                    if let Some(url) = &course.syllabus {
+                        ExternalLink {
+                            href: "{url}",
+                            class: "font-medium {STYLES.link}",
+                            i { class: "fa-solid fa-file-lines mr-1" }
+                            "Syllabus"
+                        }
+                    }
+                    for material in course.materials.iter() {
+                        ExternalLink {
+                            href: "{material.url}",
+                            class: STYLES.link,
+                            "{material.title}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Teaching() -> Element {
+    let today = timezone::today(LAB_TZ);
+    let mut courses: Vec<&Course> = content::courses().iter().collect();
+    courses.sort_by_key(|c| std::cmp::Reverse(c.latest_term()));
+    // A course stays current until the last day of its latest term
+    let (current, past): (Vec<&Course>, Vec<&Course>) =
+        courses.into_iter().partition(|c| c.terms.iter().any(|t| t.dates().1 >= today));
+
+    rsx! {
+        PageShell {
+            div {
+                class: "mb-8",
+                h1 { class: STYLES.page_title, "Teaching" }
+                p {
+                    class: "text-gray-600 text-lg",
+                    "Courses I teach at the University of Georgia, with syllabi and materials."
+                }
+            }
+
+            div {
+                class: "space-y-6",
+                if current.is_empty() && past.is_empty() {
+                    p { class: "text-gray-600", "No courses listed yet." }
+                }
+                for (title, courses) in [("Current and Upcoming", current), ("Past Courses", past)] {
+                    if !courses.is_empty() {
+                        div {
+                            class: STYLES.divider,
+                            h2 { class: CSS_GROUP, "{title}" }
+                            for course in courses {
+                                CourseCard { course: course.clone() }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
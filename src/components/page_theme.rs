@@ -0,0 +1,158 @@
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use super::theme::STYLES;
+use super::ui::{PageShell, SectionHeader};
+use super::content;
+use super::markdown::Markdown;
+use super::page_code::RepositoryCard;
+use super::page_gaim::{theme_image, GrantCard};
+use super::page_pub::{fetch_publications, matching, PublicationEntry};
+use super::routes::Route;
+
+#[component]
+pub fn ThemePage(slug: String) -> Element {
+    let mut publications = use_signal(|| None);
+    let theme = content::theme(&slug);
+
+    // Rerun when navigating from one theme to another
+    use_effect(use_reactive!(|slug| {
+        let Some(theme) = content::theme(&slug) else { return };
+        spawn_local(async move {
+            let rows = fetch_publications().await.unwrap_or_default();
+            let mut rows = matching(&rows, &theme.publications, &theme.keywords);
+            rows.sort_by(|a, b| b.year.cmp(&a.year));
+            publications.set(Some(rows));
+        });
+    }));
+
+    let Some(theme) = theme else {
+        return rsx! {
+            PageShell {
+                h1 { class: STYLES.page_title, "Not Found" }
+                p {
+                    class: "text-gray-600 text-lg",
+                    "There is no research theme with this page. See all themes on the "
+                    Link {
+                        to: Route::Director { pagename: "gaim".to_string() },
+                        class: STYLES.link,
+                        "Lab page"
+                    }
+                    "."
+                }
+            }
+        };
+    };
+
+    let repositories: Vec<_> = content::repositories()
+        .iter()
+        .filter(|r| theme.repositories.contains(&r.name))
+        .collect();
+    let members: Vec<_> = theme.members.iter().filter_map(|id| content::member(id)).collect();
+    let grants: Vec<_> = theme.funding.iter().filter_map(|id| content::grant(id)).collect();
+
+    rsx! {
+        PageShell {
+            Link {
+                to: Route::Director { pagename: "gaim".to_string() },
+                class: "text-sm text-gray-500 hover:text-red-700 transition-colors",
+                "← Lab for GAIM"
+            }
+
+            // Banner
+            div {
+                class: "mt-4 mb-8 relative overflow-hidden rounded-lg border-l-4 border-red-400 min-h-[160px] flex items-center",
+                if let Some(image) = theme_image(&theme.image) {
+                    img {
+                        src: image,
+                        alt: "{theme.alt}",
+                        class: "absolute inset-0 w-full h-full object-cover opacity-30"
+                    }
+                }
+                h1 {
+                    class: "relative z-10 px-6 {STYLES.page_title}",
+                    "{theme.title}"
+                }
+            }
+
+            div {
+                class: "space-y-6",
+
+                div {
+                    class: "{STYLES.divider} space-y-3",
+                    Markdown { text: theme.summary.clone() }
+                }
+
+                div {
+                    class: STYLES.divider,
+                    SectionHeader { title: "Publications" }
+                    match publications() {
+                        None => rsx! { div { class: "text-gray-400 py-8", "Loading ..." } },
+                        Some(rows) if rows.is_empty() => rsx! {
+                            p { class: "text-gray-600", "No publications listed yet." }
+                        },
+                        Some(rows) => rsx! {
+                            for p in rows {
+                                PublicationEntry {
+                                    year: p.year.clone(),
+                                    title: p.title.clone(),
+                                    authors: p.authors.clone(),
+                                    journal: format!("{} ({})", p.journal, p.year),
+                                    website: p.website.clone(),
+                                    note: p.note.clone(),
+                                }
+                            }
+                        },
+                    }
+                }
+
+                if !repositories.is_empty() {
+                    div {
+                        class: STYLES.divider,
+                        SectionHeader { title: "Repositories" }
+                        for repo in repositories {
+                            RepositoryCard {
+                                name: repo.name.clone(),
+                                description: repo.description.clone(),
+                                language: repo.language.clone(),
+                                url: repo.url.clone(),
+                                topics: repo.topics.clone(),
+                            }
+                        }
+                    }
+                }
+
+                if !members.is_empty() {
+                    div {
+                        class: STYLES.divider,
+                        SectionHeader { title: "People" }
+                        ul {
+                            class: "flex flex-wrap gap-4",
+                            for member in members {
+                                li {
+                                    Link {
+                                        to: Route::MemberProfile { id: member.id.clone() },
+                                        class: "text-lg {STYLES.link}",
+                                        "{member.first_name} {member.last_name}"
+                                    }
+                                    span { class: "text-sm text-gray-500 ml-2", "{member.role}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !grants.is_empty() {
+                    div {
+                        SectionHeader { title: "Funding" }
+                        div {
+                            class: "space-y-4",
+                            for grant in grants {
+                                GrantCard { grant: grant.clone() }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use dioxus::prelude::*;
+
+// Spreadsheet and calendar text for the pages that otherwise fetch theirs after they mount.
+// Only renders outside the browser provide it, so fetched content can be checked there too.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Preloaded {
+    // The publications sheet as CSV
+    pub publications: Option<String>,
+    // The resources sheet as CSV
+    pub resources: Option<String>,
+    // An iCalendar feed, shown on the Meet page in place of the configured one
+    pub calendar: Option<String>,
+}
+
+pub(super) fn use_preloaded() -> Preloaded {
+    use_hook(|| try_consume_context::<Preloaded>().unwrap_or_default())
+}
@@ -5,24 +5,136 @@ use super::page_pub::Pub;
 use super::page_code::Code;
 use super::page_info::Info;
 use super::page_gaim::Lab;
+use super::page_member::MemberProfile;
+use super::page_theme::ThemePage;
+use super::page_news::{News, NewsPost, NewsTagged};
+use super::page_teaching::Teaching;
+use super::page_talks::Talks;
+use super::page_cv::Cv;
 use super::page_cal::Cal;
-use super::page_res::Resources;
+use super::page_res::sheet::Audience;
+use super::page_res::{Resources, ResourcesFor};
+use super::page_not_found::PageNotFound;
+use super::content::{self, Page};
 
 #[derive(Debug, Clone, Routable, PartialEq)]
 #[rustfmt::skip]
 pub enum Route {
     #[layout(Header)]
+    #[layout(InBuild)]
 
     #[route("/")]
     Home {},
 
     #[route("/:pagename")]
     Director { pagename: String },
+
+    #[route("/res/:audience")]
+    ResourcesFor { audience: String },
+
+    #[route("/gaim/people/:id")]
+    MemberProfile { id: String },
+
+    #[route("/gaim/themes/:slug")]
+    ThemePage { slug: String },
+
+    #[route("/news/:id")]
+    NewsPost { id: String },
+
+    #[route("/news/tags/:tag")]
+    NewsTagged { tag: String },
+
+    #[end_layout]
+    #[route("/:..segments")]
+    PageNotFound { segments: Vec<String> },
+}
+
+impl Route {
+    // The top-level page a route belongs to, which is the one highlighted in the navigation
+    pub(super) fn section(&self) -> &str {
+        match self {
+            Route::Home {} => "home",
+            Route::Director { pagename } => pagename,
+            Route::ResourcesFor { .. } => "res",
+            Route::MemberProfile { .. } | Route::ThemePage { .. } => "gaim",
+            Route::NewsPost { .. } | Route::NewsTagged { .. } => "news",
+            Route::PageNotFound { .. } => "",
+        }
+    }
+}
+
+// Which pages a build includes. Release builds leave out the pages marked hidden in
+// assets/content/pages.toml, debug builds show them too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Build {
+    Debug,
+    Release,
+}
+
+impl Build {
+    pub const CURRENT: Build = if cfg!(debug_assertions) { Build::Debug } else { Build::Release };
+
+    pub fn shows(self, name: &str) -> bool {
+        content::page(name).is_some_and(|p| !p.hidden || self == Build::Debug)
+    }
+}
+
+// Whether a page is part of the build being rendered: this one, or the one a render
+// outside the browser provides
+pub(super) fn is_shown(name: &str) -> bool {
+    try_consume_context::<Build>().unwrap_or(Build::CURRENT).shows(name)
+}
+
+// Pages for the navigation bar, in order
+pub(super) fn nav_pages() -> impl Iterator<Item = &'static Page> {
+    content::pages().iter().filter(|p| p.nav && is_shown(&p.name))
+}
+
+pub(super) fn page_route(name: &str) -> Route {
+    match name {
+        "home" => Route::Home {},
+        name => Route::Director { pagename: name.to_string() },
+    }
+}
+
+// Every address of the pages in `build`, for the sitemap and for visiting the whole site
+// outside the browser
+pub fn site_paths(build: Build) -> Vec<String> {
+    let mut paths = vec!["/".to_string()];
+    let pages = content::pages().iter().filter(|p| build.shows(&p.name));
+    paths.extend(pages.map(|p| format!("/{}", p.name)));
+    if build.shows("res") {
+        paths.extend(Audience::ALL.iter().map(|a| format!("/res/{}", a.slug())));
+    }
+    if build.shows("gaim") {
+        paths.extend(content::members().iter().map(|m| format!("/gaim/people/{}", m.id)));
+        paths.extend(content::lab().themes.iter().map(|t| format!("/gaim/themes/{}", t.slug)));
+    }
+    if build.shows("news") {
+        paths.extend(content::news().iter().map(|n| format!("/news/{}", n.id)));
+        paths.extend(content::news_tags().iter().map(|t| format!("/news/tags/{}", t)));
+    }
+    paths
+}
+
+// Every route below the header. Pages that are not in the registry, or not in this build,
+// are not found, and neither is anything under them.
+#[component]
+fn InBuild() -> Element {
+    let route = use_route::<Route>();
+    if !is_shown(route.section()) {
+        return rsx!(PageNotFound { segments: Vec::new() });
+    }
+    rsx!(Outlet::<Route> {})
 }
 
+// The page component for each name in the registry
 #[component]
 pub fn Director(pagename: String) -> Element {
-    match pagename.as_str() {
+    let Some(page) = content::page(&pagename) else {
+        return rsx!(PageNotFound { segments: vec![pagename] });
+    };
+    match page.name.as_str() {
         "home" => rsx!(Home {}),
         "pub" => rsx!(Pub {}),
         "code" => rsx!(Code {}),
@@ -30,6 +142,11 @@ pub fn Director(pagename: String) -> Element {
         "gaim" => rsx!(Lab {}),
         "res" => rsx!(Resources {}),
         "meet" => rsx!(Cal {}),
-        _ => rsx!(Home {}),
+        "news" => rsx!(News {}),
+        "teaching" => rsx!(Teaching {}),
+        "talks" => rsx!(Talks {}),
+        "cv" => rsx!(Cv {}),
+        // Listed in pages.toml without a component yet
+        _ => rsx!(PageNotFound { segments: vec![pagename] }),
     }
 }
\ No newline at end of file
@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+use dioxus::history::{History, MemoryHistory};
+use std::rc::Rc;
+use super::markdown::Markdown;
+use super::page_pub::fetch_publications;
+use super::page_res::fetch_resources;
+use super::preload::Preloaded;
+use super::routes::{site_paths, Build, Route};
+
+#[component]
+fn SiteAt(path: String, pages: Build, preloaded: Preloaded) -> Element {
+    // The router reads the current URL from the history, so it has to exist before the router renders
+    use_hook(|| provide_context(Rc::new(MemoryHistory::with_initial_path(path)) as Rc<dyn History>));
+    use_context_provider(|| pages);
+    use_context_provider(|| preloaded);
+    rsx!(Router::<Route> {})
+}
+
+// Renders the page at `path` to HTML without a browser. Effects do not run, so
+// anything fetched at runtime shows up in its loading state.
+pub fn render_path(path: &str) -> String {
+    render_site(path, Build::CURRENT, Preloaded::default())
+}
+
+// Like `render_path`, for the pages of `build` and with `preloaded` standing in for what
+// the pages would fetch
+pub fn render_site(path: &str, build: Build, preloaded: Preloaded) -> String {
+    let mut dom = VirtualDom::new_with_props(SiteAt, SiteAtProps { path: path.to_string(), pages: build, preloaded });
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+#[component]
+fn MarkdownAlone(text: String) -> Element {
+    rsx!(Markdown { text })
+}
+
+// Renders content-file Markdown to HTML the way the pages show it. Links to site pages need
+// the router, so text with those has to be checked through `render_path`.
+pub fn render_markdown(text: &str) -> String {
+    let mut dom = VirtualDom::new_with_props(MarkdownAlone, MarkdownAloneProps { text: text.to_string() });
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+// sitemap.xml for the pages of `build`, with addresses under `base`, e.g. "https://example.org"
+pub fn sitemap(base: &str, build: Build) -> String {
+    let base = base.trim_end_matches('/');
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for path in site_paths(build) {
+        let loc = format!("{}{}", base, path).replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+        xml.push_str(&format!("  <url><loc>{}</loc></url>\n", loc));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+// Outbound links stored in the spreadsheets, as `(source, url)` pairs.
+pub async fn dataset_links() -> Vec<(String, String)> {
+    let mut links = Vec::new();
+    if let Some(rows) = fetch_publications().await {
+        links.extend(rows.into_iter().map(|r| ("publications sheet".to_string(), r.website)));
+    }
+    if let Some(sheet) = fetch_resources().await {
+        links.extend(sheet.rows.into_iter().map(|r| ("resources sheet".to_string(), r.link)));
+    }
+    links.retain(|(_, url)| !url.trim().is_empty());
+    links
+}
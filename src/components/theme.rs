@@ -0,0 +1,155 @@
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+// The site's light and dark looks. Pages use Tailwind's gray, red and `surface` color
+// classes as usual; the dark palette changes the colors behind those classes, so pages only
+// need `dark:` variants where a color must stay as it is, e.g. text over the background photo.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Theme {
+    Light,
+    Dark,
+}
+
+pub(super) struct Palette {
+    pub(super) background: Asset,
+    // Tailwind color names and the values their classes show
+    colors: &'static [(&'static str, &'static str)],
+}
+
+const LIGHT: Palette = Palette {
+    background: asset!("/assets/imgs/bg_light.jpg"),
+    colors: &[("surface", "#fff")],
+};
+
+// Grays run the other way, and red text and tints are lightened and darkened to match.
+// red-700 is left alone since it is also a background under white text.
+const DARK: Palette = Palette {
+    background: asset!("/assets/imgs/bg_dark.jpg"),
+    colors: &[
+        ("surface", "oklch(21% 0.034 264.665)"),
+        ("gray-50", "oklch(25% 0.034 262)"),
+        ("gray-100", "oklch(27.8% 0.033 256.848)"),
+        ("gray-200", "oklch(37.3% 0.034 259.733)"),
+        ("gray-300", "oklch(44.6% 0.03 256.802)"),
+        ("gray-400", "oklch(55.1% 0.027 264.364)"),
+        ("gray-500", "oklch(70.7% 0.022 261.325)"),
+        ("gray-600", "oklch(87.2% 0.01 258.338)"),
+        ("gray-700", "oklch(92.8% 0.006 264.531)"),
+        ("gray-800", "oklch(96.7% 0.003 264.542)"),
+        ("gray-900", "oklch(98.5% 0.002 247.839)"),
+        ("red-50", "oklch(25.8% 0.092 26.042)"),
+        ("red-100", "oklch(30% 0.1 25.7)"),
+        ("red-800", "oklch(88.5% 0.062 18.334)"),
+        ("red-900", "oklch(80.8% 0.114 19.571)"),
+    ],
+};
+
+impl Theme {
+    fn name(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Theme> {
+        match name {
+            "light" => Some(Theme::Light),
+            "dark" => Some(Theme::Dark),
+            _ => None,
+        }
+    }
+
+    pub(super) fn toggled(self) -> Theme {
+        match self {
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::Light,
+        }
+    }
+
+    pub(super) fn palette(self) -> &'static Palette {
+        match self {
+            Theme::Light => &LIGHT,
+            Theme::Dark => &DARK,
+        }
+    }
+}
+
+fn variables(palette: &Palette) -> String {
+    palette.colors.iter().map(|(name, value)| format!("--color-{}: {};", name, value)).collect()
+}
+
+// Both palettes as CSS variables. The dark one is for screens only so printouts stay light.
+pub(super) fn palette_css() -> String {
+    format!(
+        ":root {{ color-scheme: light; {} }} @media screen {{ :root.dark {{ color-scheme: dark; {} }} }}",
+        variables(&LIGHT),
+        variables(&DARK),
+    )
+}
+
+// Class lists shared by the pages
+pub(super) struct Styles {
+    pub(super) container: &'static str,
+    pub(super) card: &'static str,
+    pub(super) page_title: &'static str,
+    pub(super) link: &'static str,
+    pub(super) section_title: &'static str,
+    // Line under each entry in a list
+    pub(super) divider: &'static str,
+}
+
+pub(super) const STYLES: Styles = Styles {
+    container: "flex justify-center items-start pt-20 w-4/5 mx-auto min-w-[350px] relative z-10",
+    card: "bg-surface rounded-lg shadow-xl max-w-[80rem] w-full p-8 mb-15 mx-auto",
+    page_title: "text-4xl font-bold text-gray-900 mb-2",
+    link: "underline hover:text-red-700 transition-colors",
+    section_title: "text-2xl font-bold text-red-900 mb-4",
+    divider: "border-b border-gray-200 pb-6",
+};
+
+// The theme in use, shared through context. It starts light, which is also what pages
+// rendered outside the browser get, then switches to the visitor's saved choice or, without
+// one, their system setting.
+pub(super) fn use_theme_provider() -> Signal<Theme> {
+    let mut theme = use_context_provider(|| Signal::new(Theme::Light));
+    use_effect(move || {
+        spawn_local(async move {
+            let name = document::eval(
+                r#"return localStorage.getItem("theme") ?? (matchMedia("(prefers-color-scheme: dark)").matches ? "dark" : "light");"#,
+            )
+            .join::<String>()
+            .await;
+            if let Some(saved) = name.ok().and_then(|n| Theme::from_name(&n)) {
+                theme.set(saved);
+            }
+        });
+    });
+    use_effect(move || {
+        document::eval(&format!(
+            r#"document.documentElement.classList.toggle("dark", {});"#,
+            theme() == Theme::Dark
+        ));
+    });
+    theme
+}
+
+#[component]
+pub(super) fn ThemeToggle(class: &'static str) -> Element {
+    let mut theme = use_context::<Signal<Theme>>();
+    let next = theme().toggled();
+    rsx! {
+        button {
+            r#type: "button",
+            class: "{class} cursor-pointer",
+            aria_label: "Switch to {next.name()} theme",
+            title: "Switch to {next.name()} theme",
+            onclick: move |_| {
+                theme.set(next);
+                document::eval(&format!(r#"localStorage.setItem("theme", "{}");"#, next.name()));
+            },
+            i { class: if next == Theme::Dark { "fa-solid fa-moon" } else { "fa-solid fa-sun" } }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use dioxus::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+// Times are kept in UTC and shown in two zones: the visitor's, as reported by the browser,
+// and the lab's, which is where deadlines and meetings are set.
+
+pub const LAB_TZ: Tz = chrono_tz::America::New_York;
+
+// Deadlines given as a date close at this time in the lab
+const DEADLINE_TIME: (u32, u32) = (23, 59);
+
+// `local` wall-clock time in `tz`. Repeated times take their first occurrence, and times
+// skipped by a DST change read as the clock before the change, e.g. 02:30 becomes 03:30.
+pub fn resolve<Z: TimeZone>(tz: &Z, local: NaiveDateTime) -> DateTime<Utc> {
+    match tz.from_local_datetime(&local).earliest() {
+        Some(time) => time.with_timezone(&Utc),
+        None => {
+            // No zone changes its clock twice in a day
+            let before = tz.offset_from_utc_datetime(&(local - Duration::days(1))).fix();
+            Utc.from_utc_datetime(&(local - Duration::seconds(before.local_minus_utc() as i64)))
+        }
+    }
+}
+
+pub fn today(tz: Tz) -> NaiveDate {
+    Utc::now().with_timezone(&tz).date_naive()
+}
+
+// The moment a deadline on `date` passes
+pub fn deadline(date: NaiveDate) -> DateTime<Utc> {
+    let time = NaiveTime::from_hms_opt(DEADLINE_TIME.0, DEADLINE_TIME.1, 0).expect("valid time");
+    resolve(&LAB_TZ, date.and_time(time))
+}
+
+// `UTC-05:00`
+pub fn offset_label(instant: DateTime<Utc>, tz: Tz) -> String {
+    format!("UTC{}", instant.with_timezone(&tz).format("%:z"))
+}
+
+// Whether clocks in both zones read the same at `instant`
+pub fn same_clock(instant: DateTime<Utc>, a: Tz, b: Tz) -> bool {
+    instant.with_timezone(&a).offset().fix() == instant.with_timezone(&b).offset().fix()
+}
+
+// `instant` written with `format` in `tz`, followed by the zone's abbreviation
+pub fn in_zone(instant: DateTime<Utc>, tz: Tz, format: &str) -> String {
+    instant.with_timezone(&tz).format(&format!("{} %Z", format)).to_string()
+}
+
+// `instant` for the visitor, with the lab's clock added when it reads differently
+pub fn with_lab_time(instant: DateTime<Utc>, visitor: Tz, format: &str) -> String {
+    let mine = in_zone(instant, visitor, format);
+    if same_clock(instant, visitor, LAB_TZ) {
+        mine
+    } else {
+        format!("{} ({} in the lab)", mine, in_zone(instant, LAB_TZ, format))
+    }
+}
+
+// The visitor's zone once the browser reports it, the lab's until then and outside the browser
+pub fn use_visitor_tz() -> Signal<Tz> {
+    let mut tz = use_signal(|| LAB_TZ);
+    use_effect(move || {
+        spawn_local(async move {
+            let name = document::eval("return Intl.DateTimeFormat().resolvedOptions().timeZone;")
+                .join::<String>()
+                .await;
+            if let Some(zone) = name.ok().and_then(|n| n.parse().ok()) {
+                tz.set(zone);
+            }
+        });
+    });
+    tz
+}
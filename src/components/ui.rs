@@ -0,0 +1,200 @@
+use dioxus::prelude::*;
+use super::theme::STYLES;
+
+// Markup the pages share: the page frame, cards, pills, section headings and callouts
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum CardVariant {
+    // Red bar on the left, for entries in a list
+    #[default]
+    Accent,
+    // Thin border all round
+    Outline,
+    // Fixed-size tile with centered content
+    Tile,
+}
+
+impl CardVariant {
+    fn base(self) -> &'static str {
+        match self {
+            CardVariant::Accent => "p-4 border-l-4 border-red-400 rounded-r-lg bg-surface",
+            CardVariant::Outline => "p-6 border border-gray-200 rounded-lg bg-surface",
+            CardVariant::Tile => "w-64 h-40 flex flex-col items-center justify-center bg-surface border border-gray-200 rounded-lg shadow",
+        }
+    }
+
+    fn hover(self) -> &'static str {
+        match self {
+            CardVariant::Accent | CardVariant::Tile => "hover:bg-gray-50 hover:shadow-lg hover:border-red-500",
+            CardVariant::Outline => "hover:bg-gray-50 hover:shadow-lg hover:border-red-300",
+        }
+    }
+}
+
+// Read out after links that open in a new tab
+const NEW_TAB_NOTE: &str = " (opens in a new tab)";
+
+const CSS_LIFT: &str = "transition-all duration-200 cursor-pointer transform hover:-translate-y-1";
+
+// The white card every page sits on. The frame around it gets the id `{id}-container`.
+#[component]
+pub(super) fn PageShell(#[props(default)] id: Option<String>, children: Element) -> Element {
+    rsx! {
+        div {
+            id: id.as_ref().map(|id| format!("{}-container", id)),
+            class: STYLES.container,
+            div {
+                id,
+                class: STYLES.card,
+                {children}
+            }
+        }
+    }
+}
+
+#[component]
+pub(super) fn Card(
+    #[props(default)] variant: CardVariant,
+    #[props(default)] id: Option<String>,
+    #[props(default)] class: String,
+    children: Element,
+) -> Element {
+    rsx! {
+        div {
+            id,
+            class: "{variant.base()} {class}",
+            {children}
+        }
+    }
+}
+
+// A card that is one big link. The link covers the card instead of wrapping it, so links
+// inside can sit on top with `relative z-10` without nesting anchors.
+#[component]
+pub(super) fn LinkCard(
+    #[props(into)] to: NavigationTarget,
+    label: String,
+    #[props(default)] new_tab: bool,
+    #[props(default)] variant: CardVariant,
+    #[props(default)] class: String,
+    children: Element,
+) -> Element {
+    rsx! {
+        div {
+            class: "group relative {variant.base()} {variant.hover()} {CSS_LIFT} {class}",
+            Link {
+                to,
+                new_tab,
+                class: "absolute inset-0",
+                aria_label: if new_tab { format!("{}{}", label, NEW_TAB_NOTE) } else { label },
+            }
+            {children}
+        }
+    }
+}
+
+// A link to another site, opened in a new tab. Screen readers are told so; `mailto:` links
+// stay in place since they open the mail client instead.
+#[component]
+pub(super) fn ExternalLink(
+    href: String,
+    #[props(default)] class: String,
+    #[props(default)] title: Option<String>,
+    children: Element,
+) -> Element {
+    let new_tab = !href.starts_with("mailto:");
+    rsx! {
+        a {
+            href,
+            target: new_tab.then_some("_blank"),
+            rel: new_tab.then_some("noopener noreferrer"),
+            class,
+            title,
+            {children}
+            if new_tab {
+                span { class: "sr-only", {NEW_TAB_NOTE} }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum PillTone {
+    // Keywords and languages
+    #[default]
+    Soft,
+    // Categories and the selected tag
+    Strong,
+    // Tags that link somewhere
+    Accent,
+}
+
+impl PillTone {
+    fn class(self) -> &'static str {
+        match self {
+            PillTone::Soft => "font-medium bg-red-100 text-gray-500",
+            PillTone::Strong => "font-semibold bg-red-700 text-white",
+            PillTone::Accent => "bg-red-50 text-red-700 hover:bg-red-100 transition-colors",
+        }
+    }
+}
+
+const CSS_PILL: &str = "inline-flex items-center px-2 py-1 text-xs rounded-full";
+
+#[component]
+pub(super) fn Pill(text: String, #[props(default)] tone: PillTone) -> Element {
+    rsx! {
+        span { class: "{CSS_PILL} {tone.class()}", "{text}" }
+    }
+}
+
+#[component]
+pub(super) fn PillLink(#[props(into)] to: NavigationTarget, text: String, #[props(default)] tone: PillTone) -> Element {
+    rsx! {
+        Link { to, class: "{CSS_PILL} {tone.class()}", "{text}" }
+    }
+}
+
+#[component]
+pub(super) fn SectionHeader(title: String, #[props(default)] id: Option<String>) -> Element {
+    rsx! {
+        h2 { id, class: STYLES.section_title, "{title}" }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum CalloutTone {
+    // Lab colors, for what the page is asking of the reader
+    #[default]
+    Accent,
+    // Yellow, for pointers elsewhere on the site
+    Notice,
+}
+
+impl CalloutTone {
+    fn class(self) -> &'static str {
+        match self {
+            CalloutTone::Accent => "bg-red-50 border-red-400 rounded shadow-sm",
+            CalloutTone::Notice => "bg-yellow-50 border-yellow-400 rounded-lg",
+        }
+    }
+}
+
+// A tinted box that stands out from the text around it
+#[component]
+pub(super) fn Callout(
+    #[props(default)] title: Option<String>,
+    #[props(default)] tone: CalloutTone,
+    #[props(default)] class: String,
+    children: Element,
+) -> Element {
+    rsx! {
+        div {
+            class: "border-l-4 p-4 {tone.class()} {class}",
+            if let Some(title) = title {
+                h3 { class: "font-semibold text-gray-900 mb-2", "{title}" }
+            }
+            {children}
+        }
+    }
+}
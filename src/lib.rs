@@ -0,0 +1,4 @@
+pub mod components;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod linkcheck;
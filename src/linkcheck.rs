@@ -0,0 +1,163 @@
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+// What a single request returned, before it is judged
+#[derive(Debug, Clone, PartialEq)]
+pub struct Probe {
+    pub status: u16,
+    pub location: Option<String>,
+}
+
+// Anything that can fetch a URL without following redirects. Lets the checker run against a local server.
+pub trait HttpClient {
+    fn probe(&self, url: &str) -> impl Future<Output = Result<Probe, String>>;
+}
+
+pub struct ReqwestClient {
+    client: reqwest::Client,
+}
+
+impl ReqwestClient {
+    pub fn new(timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(timeout)
+            // Some hosts refuse requests without a user agent
+            .user_agent(concat!("personal_website-linkcheck/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .expect("the client configuration is static");
+        ReqwestClient { client }
+    }
+}
+
+impl HttpClient for ReqwestClient {
+    async fn probe(&self, url: &str) -> Result<Probe, String> {
+        let resp = self.client.get(url).send().await.map_err(|e| e.to_string())?;
+        let location = resp
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+        Ok(Probe { status: resp.status().as_u16(), location })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkStatus {
+    Ok { status: u16 },
+    Redirected { status: u16, location: Option<String> },
+    Dead { reason: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct LinkReport {
+    pub url: String,
+    // Pages or datasets the link was found in
+    pub sources: Vec<String>,
+    pub status: LinkStatus,
+    pub elapsed: Duration,
+    pub slow: bool,
+}
+
+impl LinkReport {
+    pub fn to_json(&self) -> Value {
+        let (kind, status, location, reason) = match &self.status {
+            LinkStatus::Ok { status } => ("ok", Some(*status), None, None),
+            LinkStatus::Redirected { status, location } => ("redirected", Some(*status), location.clone(), None),
+            LinkStatus::Dead { reason } => ("dead", None, None, Some(reason.clone())),
+        };
+        json!({
+            "url": self.url,
+            "sources": self.sources,
+            "kind": kind,
+            "status": status,
+            "location": location,
+            "reason": reason,
+            "elapsed_ms": self.elapsed.as_millis() as u64,
+            "slow": self.slow,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckOptions {
+    pub concurrency: usize,
+    // Responses slower than this are flagged but not treated as dead
+    pub slow_after: Duration,
+}
+
+impl Default for CheckOptions {
+    fn default() -> Self {
+        CheckOptions { concurrency: 8, slow_after: Duration::from_secs(3) }
+    }
+}
+
+// Pulls absolute http(s) URLs out of `href` and `src` attributes in rendered HTML.
+pub fn extract_urls(html: &str) -> Vec<String> {
+    let mut urls = Vec::new();
+    for attr in ["href=\"", "src=\""] {
+        for (start, _) in html.match_indices(attr) {
+            let rest = &html[start + attr.len()..];
+            let Some(end) = rest.find('"') else { continue };
+            let url = rest[..end].replace("&amp;", "&");
+            if (url.starts_with("http://") || url.starts_with("https://")) && !urls.contains(&url) {
+                urls.push(url);
+            }
+        }
+    }
+    urls
+}
+
+// Merges `(source, url)` pairs so each URL is only requested once.
+pub fn group_by_url(links: impl IntoIterator<Item = (String, String)>) -> BTreeMap<String, Vec<String>> {
+    let mut grouped: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (source, url) in links {
+        let sources = grouped.entry(url.trim().to_string()).or_default();
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+    grouped
+}
+
+fn judge(result: Result<Probe, String>) -> LinkStatus {
+    match result {
+        Ok(Probe { status, .. }) if (200..300).contains(&status) => LinkStatus::Ok { status },
+        Ok(Probe { status, location }) if (300..400).contains(&status) => LinkStatus::Redirected { status, location },
+        Ok(Probe { status, .. }) => LinkStatus::Dead { reason: format!("HTTP {}", status) },
+        Err(reason) => LinkStatus::Dead { reason },
+    }
+}
+
+pub async fn check_links<C: HttpClient>(
+    client: &C,
+    links: BTreeMap<String, Vec<String>>,
+    options: &CheckOptions,
+) -> Vec<LinkReport> {
+    let mut reports: Vec<LinkReport> = stream::iter(links)
+        .map(|(url, sources)| async move {
+            let started = Instant::now();
+            let status = judge(client.probe(&url).await);
+            let elapsed = started.elapsed();
+            LinkReport { url, sources, status, elapsed, slow: elapsed > options.slow_after }
+        })
+        .buffer_unordered(options.concurrency.max(1))
+        .collect()
+        .await;
+    reports.sort_by(|a, b| a.url.cmp(&b.url));
+    reports
+}
+
+pub fn report_json(reports: &[LinkReport]) -> Value {
+    let count = |f: fn(&LinkReport) -> bool| reports.iter().filter(|r| f(r)).count();
+    json!({
+        "checked": reports.len(),
+        "dead": count(|r| matches!(r.status, LinkStatus::Dead { .. })),
+        "redirected": count(|r| matches!(r.status, LinkStatus::Redirected { .. })),
+        "slow": count(|r| r.slow),
+        "links": reports.iter().map(LinkReport::to_json).collect::<Vec<_>>(),
+    })
+}
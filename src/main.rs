@@ -1,7 +1,5 @@
-mod components;
-
 use dioxus::prelude::*;
-use components::Route;
+use personal_website::components::Route;
 
 const FAVICON: Asset = asset!("/assets/icons/favicon_uga.ico");
 const TAILWIND_CSS: Asset = asset!("/assets/css/tailwind_output.css");
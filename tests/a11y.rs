@@ -0,0 +1,251 @@
+use personal_website::components::{render_path, render_site, site_paths, Build, Preloaded};
+use std::collections::HashSet;
+
+// Elements that never have a closing tag
+const VOID: [&str; 8] = ["img", "input", "br", "hr", "meta", "link", "source", "wbr"];
+// Nothing interactive or block-level may sit inside a button
+const NOT_IN_BUTTON: [&str; 13] = ["a", "button", "input", "select", "textarea", "p", "div", "h1", "h2", "h3", "h4", "h5", "h6"];
+
+struct Tag {
+    name: String,
+    attrs: Vec<(String, String)>,
+}
+
+impl Tag {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+}
+
+enum Token {
+    Open(Tag),
+    Close(String),
+    Text(String),
+}
+
+fn parse_tag(inner: &str) -> Tag {
+    let inner = inner.trim_end_matches('/');
+    let (name, mut rest) = inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+    let mut attrs = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let end = rest.find(|c: char| c == '=' || c.is_whitespace()).unwrap_or(rest.len());
+        let attr = rest[..end].to_lowercase();
+        rest = &rest[end..];
+        let value = if let Some(after) = rest.strip_prefix("=\"") {
+            let close = after.find('"').unwrap_or(after.len());
+            rest = after.get(close + 1..).unwrap_or("");
+            after[..close].to_string()
+        } else if let Some(after) = rest.strip_prefix('=') {
+            let close = after.find(char::is_whitespace).unwrap_or(after.len());
+            rest = &after[close..];
+            after[..close].to_string()
+        } else {
+            String::new()
+        };
+        attrs.push((attr, value));
+    }
+    Tag { name: name.to_lowercase(), attrs }
+}
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        if start > 0 {
+            tokens.push(Token::Text(rest[..start].to_string()));
+        }
+        rest = &rest[start..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.split_once("-->").map_or("", |(_, r)| r);
+            continue;
+        }
+        let end = rest.find('>').expect("unclosed tag");
+        let inner = &rest[1..end];
+        rest = &rest[end + 1..];
+        if let Some(name) = inner.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+        } else if !inner.starts_with('!') {
+            let tag = parse_tag(inner);
+            // Style and script bodies are not markup
+            if tag.name == "style" || tag.name == "script" {
+                let close = format!("</{}>", tag.name);
+                rest = rest.split_once(close.as_str()).map_or("", |(_, r)| r);
+                continue;
+            }
+            tokens.push(Token::Open(tag));
+        }
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_string()));
+    }
+    tokens
+}
+
+// An open link or button, collecting the text that names it
+struct Named {
+    depth: usize,
+    new_tab: bool,
+    label: String,
+    text: String,
+    title: String,
+}
+
+// Every rule the page breaks, as readable messages
+fn problems(html: &str) -> Vec<String> {
+    let tokens = tokenize(html);
+    let mut found = Vec::new();
+
+    let labelled: HashSet<&str> = tokens
+        .iter()
+        .filter_map(|t| match t {
+            Token::Open(tag) if tag.name == "label" => tag.attr("for"),
+            _ => None,
+        })
+        .collect();
+
+    let mut stack: Vec<String> = Vec::new();
+    let mut named: Vec<Named> = Vec::new();
+    let (mut mains, mut h1s, mut skip_link) = (0, 0, false);
+
+    for token in &tokens {
+        match token {
+            Token::Open(tag) => {
+                let name = tag.name.as_str();
+                let inside = |n: &str| stack.iter().any(|s| s == n);
+
+                if name == "a" && inside("a") {
+                    found.push("a link inside a link".to_string());
+                }
+                if inside("button") && NOT_IN_BUTTON.contains(&name) {
+                    found.push(format!("<{}> inside a button", name));
+                }
+                match name {
+                    "main" => mains += 1,
+                    "h1" => h1s += 1,
+                    "a" if tag.attr("href") == Some("#main") && mains == 0 => skip_link = true,
+                    "img" if tag.attr("alt").is_none() => {
+                        found.push(format!("image without alt: {:?}", tag.attr("src")));
+                    }
+                    "iframe" if tag.attr("title").is_none_or(str::is_empty) => {
+                        found.push(format!("iframe without a title: {:?}", tag.attr("src")));
+                    }
+                    "input" | "select" | "textarea" => {
+                        let hidden = tag.attr("type") == Some("hidden");
+                        let by_for = tag.attr("id").is_some_and(|id| labelled.contains(id));
+                        if !hidden && !by_for && !inside("label") && tag.attr("aria-label").is_none() {
+                            found.push(format!("unlabelled <{}> {:?}", name, tag.attr("name").or(tag.attr("id"))));
+                        }
+                    }
+                    _ => {}
+                }
+                let new_tab = name == "a" && tag.attr("target") == Some("_blank");
+                if new_tab && !tag.attr("rel").unwrap_or("").split_whitespace().any(|r| r == "noopener") {
+                    found.push(format!("new-tab link without rel=noopener: {:?}", tag.attr("href")));
+                }
+                if name == "a" || name == "button" {
+                    named.push(Named {
+                        depth: stack.len(),
+                        new_tab,
+                        label: tag.attr("aria-label").unwrap_or("").to_string(),
+                        text: String::new(),
+                        title: tag.attr("title").unwrap_or("").to_string(),
+                    });
+                }
+                if name == "img" {
+                    if let Some(n) = named.last_mut() {
+                        n.text.push_str(tag.attr("alt").unwrap_or(""));
+                    }
+                }
+                if !VOID.contains(&name) {
+                    stack.push(tag.name.clone());
+                }
+            }
+            Token::Text(text) => {
+                for n in named.iter_mut() {
+                    n.text.push_str(text);
+                }
+            }
+            Token::Close(name) => {
+                let Some(pos) = stack.iter().rposition(|s| s == name) else { continue };
+                stack.truncate(pos);
+                if (name == "a" || name == "button") && named.last().is_some_and(|n| n.depth == pos) {
+                    let n = named.pop().unwrap();
+                    // The same order browsers use: aria-label, then the content, then the title
+                    let accessible = [n.label, n.text, n.title].into_iter().find(|s| !s.trim().is_empty()).unwrap_or_default();
+                    if accessible.trim().is_empty() {
+                        found.push(format!("<{}> without an accessible name", name));
+                    } else if n.new_tab && !accessible.to_lowercase().contains("new tab") {
+                        found.push(format!("new-tab link {:?} does not say so", accessible.trim()));
+                    }
+                }
+            }
+        }
+    }
+
+    if mains != 1 {
+        found.push(format!("{} <main> landmarks", mains));
+    }
+    if !skip_link {
+        found.push("no skip link to #main before the content".to_string());
+    }
+    if h1s != 1 {
+        found.push(format!("{} <h1> headings", h1s));
+    }
+    found
+}
+
+#[test]
+fn every_page_passes_the_audit() {
+    let mut failures = Vec::new();
+    for path in site_paths(Build::CURRENT) {
+        for problem in problems(&render_path(&path)) {
+            failures.push(format!("{}: {}", path, problem));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+}
+
+#[test]
+fn fetched_content_passes_the_audit() {
+    let preloaded = Preloaded {
+        publications: Some(fixture("publications.csv")),
+        resources: Some(fixture("resources.csv")),
+        calendar: Some(fixture("google.ics")),
+    };
+    let mut failures = Vec::new();
+    // What each page shows once its data is in
+    for (path, shown) in [
+        ("/pub", "Mapping sea ice with open imagery"),
+        ("/res", "Open data grant"),
+        ("/res/grad", "Summer research fellowship"),
+        ("/meet", "Previous week"),
+    ] {
+        let html = render_site(path, Build::CURRENT, preloaded.clone());
+        assert!(html.contains(shown), "{} does not show {:?}", path, shown);
+        for problem in problems(&html) {
+            failures.push(format!("{}: {}", path, problem));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn the_audit_catches_broken_markup() {
+    let page = r##"<a href="#main">Skip</a><main><h1>T</h1>
+        <button><p>Go</p></button>
+        <a href="https://example.com" target="_blank">Out</a>
+        <a href="/x"><i class="fa-solid fa-x"></i></a>
+        <img src="a.png"><input id="q"></main>"##;
+    let found = problems(page);
+    for expected in ["<p> inside a button", "without rel=noopener", "does not say so", "without an accessible name", "image without alt", "unlabelled <input>"] {
+        assert!(found.iter().any(|p| p.contains(expected)), "missed {:?} in {:?}", expected, found);
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use chrono_tz::Tz;
+use personal_website::components::availability::{load_busy, week, week_start, BlockKind, FileSource};
+use personal_website::components::ics::Interval;
+
+fn utc(s: &str) -> DateTime<Utc> {
+    s.parse().unwrap()
+}
+
+fn interval(start: &str, end: &str) -> Interval {
+    Interval { start: utc(start), end: utc(end) }
+}
+
+fn fixture() -> FileSource {
+    FileSource::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/busy.ics"))
+}
+
+fn march() -> Interval {
+    interval("2025-03-01T00:00:00Z", "2025-03-10T00:00:00Z")
+}
+
+#[tokio::test]
+async fn reads_busy_times_from_a_file() {
+    let busy = load_busy(&fixture(), march(), Tz::UTC).await.unwrap();
+    assert_eq!(
+        busy,
+        vec![
+            // Overlapping events are merged
+            interval("2025-03-03T14:00:00Z", "2025-03-03T15:30:00Z"),
+            // Free/busy periods, but not the one marked free
+            interval("2025-03-06T13:00:00Z", "2025-03-06T13:30:00Z"),
+            interval("2025-03-06T20:00:00Z", "2025-03-06T21:00:00Z"),
+            // All-day event without an end
+            interval("2025-03-07T00:00:00Z", "2025-03-08T00:00:00Z"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn missing_files_are_errors() {
+    let source = FileSource::new("tests/fixtures/no-such-calendar.ics");
+    assert!(load_busy(&source, march(), Tz::UTC).await.is_err());
+}
+
+#[tokio::test]
+async fn week_is_laid_out_in_the_viewers_timezone() {
+    let busy = load_busy(&fixture(), march(), Tz::UTC).await.unwrap();
+    // UTC-5, like New York in winter
+    let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+    let monday = week_start(NaiveDate::from_ymd_opt(2025, 3, 5).unwrap());
+    assert_eq!(monday, NaiveDate::from_ymd_opt(2025, 3, 3).unwrap());
+
+    let days = week(&busy, monday, &tz, (8, 18));
+    assert_eq!(days.len(), 7);
+
+    // 14:00-15:30 UTC is 9:00-10:30 in the viewer's timezone
+    let monday = &days[0];
+    assert_eq!(monday.window, interval("2025-03-03T13:00:00Z", "2025-03-03T23:00:00Z"));
+    let kinds: Vec<BlockKind> = monday.blocks.iter().map(|b| b.kind).collect();
+    assert_eq!(kinds, vec![BlockKind::Free, BlockKind::Busy, BlockKind::Free]);
+    assert_eq!(monday.blocks[1].interval, interval("2025-03-03T14:00:00Z", "2025-03-03T15:30:00Z"));
+    assert_eq!(monday.position(utc("2025-03-03T14:00:00Z")), 0.1);
+
+    // The all-day event runs midnight to midnight UTC, which covers all of Friday's shown hours
+    let friday = &days[4];
+    assert_eq!(friday.blocks.len(), 1);
+    assert_eq!(friday.blocks[0].kind, BlockKind::Busy);
+
+    // Days without events are one free block
+    assert_eq!(days[6].blocks.len(), 1);
+    assert_eq!(days[6].blocks[0].kind, BlockKind::Free);
+}
@@ -0,0 +1,149 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use personal_website::components::ics::{busy_intervals, Interval};
+
+fn utc(s: &str) -> DateTime<Utc> {
+    s.parse().unwrap()
+}
+
+fn interval(start: &str, end: &str) -> Interval {
+    Interval { start: utc(start), end: utc(end) }
+}
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+}
+
+#[test]
+fn expands_a_google_export() {
+    let busy = busy_intervals(&fixture("google.ics"), interval("2025-03-01T00:00:00Z", "2025-04-01T00:00:00Z"), Tz::UTC);
+    assert_eq!(
+        busy,
+        vec![
+            // Mondays and Wednesdays 10:00 New York time, before and after DST starts on March 9
+            interval("2025-03-03T15:00:00Z", "2025-03-03T16:00:00Z"),
+            // March 5 is an EXDATE; the floating event is read in the calendar's zone
+            interval("2025-03-06T14:00:00Z", "2025-03-06T14:30:00Z"),
+            interval("2025-03-10T14:00:00Z", "2025-03-10T15:00:00Z"),
+            // Moved to 13:00 by an edited occurrence
+            interval("2025-03-12T17:00:00Z", "2025-03-12T18:00:00Z"),
+            // March 17 was cancelled
+            interval("2025-03-19T14:00:00Z", "2025-03-19T15:00:00Z"),
+            // Three daily stand-ups
+            interval("2025-03-20T12:00:00Z", "2025-03-20T12:30:00Z"),
+            interval("2025-03-21T12:00:00Z", "2025-03-21T12:30:00Z"),
+            interval("2025-03-22T12:00:00Z", "2025-03-22T12:30:00Z"),
+            interval("2025-03-24T14:00:00Z", "2025-03-24T15:00:00Z"),
+            // UNTIL ends the series before March 31
+            interval("2025-03-26T14:00:00Z", "2025-03-26T15:00:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn expands_an_outlook_export() {
+    let busy = busy_intervals(&fixture("outlook.ics"), interval("2025-10-01T00:00:00Z", "2025-12-01T00:00:00Z"), Tz::UTC);
+    assert_eq!(
+        busy,
+        vec![
+            // Every other Friday at 9:00 in the Windows "Eastern Standard Time", across the end of DST
+            interval("2025-10-31T13:00:00Z", "2025-10-31T14:00:00Z"),
+            // The free "Working elsewhere" event is skipped, the Berlin call uses the zone behind the prefix
+            interval("2025-11-05T14:00:00Z", "2025-11-05T15:00:00Z"),
+            // All-day repeats without the excluded day
+            interval("2025-11-10T00:00:00Z", "2025-11-11T00:00:00Z"),
+            interval("2025-11-12T00:00:00Z", "2025-11-13T00:00:00Z"),
+            interval("2025-11-14T14:00:00Z", "2025-11-14T15:00:00Z"),
+            interval("2025-11-28T14:00:00Z", "2025-11-28T15:00:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn clips_to_the_range() {
+    let busy = busy_intervals(&fixture("google.ics"), interval("2025-03-03T15:30:00Z", "2025-03-06T14:15:00Z"), Tz::UTC);
+    assert_eq!(
+        busy,
+        vec![
+            interval("2025-03-03T15:30:00Z", "2025-03-03T16:00:00Z"),
+            interval("2025-03-06T14:00:00Z", "2025-03-06T14:15:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn repeats_without_an_end_stop_at_the_range() {
+    let feed = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20200106T140000Z\nDTEND:20200106T150000Z\nRRULE:FREQ=DAILY\nEND:VEVENT\nEND:VCALENDAR\n";
+    let busy = busy_intervals(feed, interval("2025-01-01T00:00:00Z", "2025-01-08T00:00:00Z"), Tz::UTC);
+    assert_eq!(busy.len(), 7);
+    assert_eq!(busy[0], interval("2025-01-01T14:00:00Z", "2025-01-01T15:00:00Z"));
+}
+
+#[test]
+fn ignores_what_it_cannot_read() {
+    let feed = "not a calendar\nBEGIN:VEVENT\nDTSTART:garbage\nEND:VEVENT\nBEGIN:VEVENT\nDTSTART:20250101T100000Z\nDTEND:20250101T110000Z\nRRULE:FREQ=MONTHLY;BYMONTHDAY=1\nEND:VEVENT\n";
+    let busy = busy_intervals(feed, interval("2025-01-01T00:00:00Z", "2025-04-01T00:00:00Z"), Tz::UTC);
+    // Monthly rules are not expanded, the first occurrence still counts
+    assert_eq!(busy, vec![interval("2025-01-01T10:00:00Z", "2025-01-01T11:00:00Z")]);
+}
+
+#[test]
+fn all_day_events_follow_the_calendar_zone() {
+    let feed = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20250107\nEND:VEVENT\nEND:VCALENDAR\n";
+    let week = interval("2025-01-06T00:00:00Z", "2025-01-13T00:00:00Z");
+    // Without X-WR-TIMEZONE the day is the owner's day, not UTC's
+    assert_eq!(
+        busy_intervals(feed, week, chrono_tz::America::New_York),
+        vec![interval("2025-01-07T05:00:00Z", "2025-01-08T05:00:00Z")]
+    );
+    // The feed's own zone wins
+    let named = feed.replace("BEGIN:VEVENT", "X-WR-TIMEZONE:Europe/Berlin\nBEGIN:VEVENT");
+    assert_eq!(
+        busy_intervals(&named, week, chrono_tz::America::New_York),
+        vec![interval("2025-01-06T23:00:00Z", "2025-01-07T23:00:00Z")]
+    );
+}
+
+#[test]
+fn count_includes_a_start_outside_the_rule() {
+    // Starts on a Monday, repeats on Tuesdays and Thursdays: three occurrences in all
+    let feed = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20250106T140000Z\nDTEND:20250106T150000Z\nRRULE:FREQ=WEEKLY;BYDAY=TU,TH;COUNT=3\nEND:VEVENT\nEND:VCALENDAR\n";
+    let busy = busy_intervals(feed, interval("2025-01-01T00:00:00Z", "2025-02-01T00:00:00Z"), Tz::UTC);
+    assert_eq!(
+        busy,
+        vec![
+            interval("2025-01-06T14:00:00Z", "2025-01-06T15:00:00Z"),
+            interval("2025-01-07T14:00:00Z", "2025-01-07T15:00:00Z"),
+            interval("2025-01-09T14:00:00Z", "2025-01-09T15:00:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn all_day_repeats_last_the_whole_day_across_dst() {
+    let feed = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20250302\nDTEND;VALUE=DATE:20250303\nRRULE:FREQ=WEEKLY;COUNT=2\nEND:VEVENT\nEND:VCALENDAR\n";
+    let busy = busy_intervals(feed, interval("2025-03-01T00:00:00Z", "2025-03-15T00:00:00Z"), chrono_tz::America::New_York);
+    assert_eq!(
+        busy,
+        vec![
+            interval("2025-03-02T05:00:00Z", "2025-03-03T05:00:00Z"),
+            // Clocks go forward on March 9, so that Sunday is 23 hours long
+            interval("2025-03-09T05:00:00Z", "2025-03-10T04:00:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn rdate_periods_keep_their_own_length() {
+    let feed = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20250106T140000Z\nDTEND:20250106T150000Z\nRDATE:20250108T140000Z\nRDATE;VALUE=PERIOD:20250110T090000Z/PT2H,20250111T090000Z/20250111T093000Z\nEND:VEVENT\nEND:VCALENDAR\n";
+    let busy = busy_intervals(feed, interval("2025-01-01T00:00:00Z", "2025-02-01T00:00:00Z"), Tz::UTC);
+    assert_eq!(
+        busy,
+        vec![
+            interval("2025-01-06T14:00:00Z", "2025-01-06T15:00:00Z"),
+            interval("2025-01-08T14:00:00Z", "2025-01-08T15:00:00Z"),
+            interval("2025-01-10T09:00:00Z", "2025-01-10T11:00:00Z"),
+            interval("2025-01-11T09:00:00Z", "2025-01-11T09:30:00Z"),
+        ]
+    );
+}
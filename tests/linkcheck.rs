@@ -0,0 +1,88 @@
+use personal_website::components::{render_path, site_paths, Build};
+use personal_website::linkcheck::*;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+// Serves a fixed set of paths until the test process exits
+fn serve() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            thread::spawn(move || {
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                // Drain the headers
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+                let response = match path {
+                    "/ok" => "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_string(),
+                    "/moved" => "HTTP/1.1 301 Moved Permanently\r\nLocation: /ok\r\nContent-Length: 0\r\n\r\n".to_string(),
+                    "/slow" => {
+                        thread::sleep(Duration::from_millis(300));
+                        "HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n".to_string()
+                    }
+                    _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n".to_string(),
+                };
+                (&stream).write_all(response.as_bytes()).unwrap();
+            });
+        }
+    });
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn reports_dead_redirected_and_slow_links() {
+    let base = serve();
+    let links = group_by_url(vec![
+        ("/".to_string(), format!("{}/ok", base)),
+        ("/gaim".to_string(), format!("{}/ok", base)),
+        ("/".to_string(), format!("{}/moved", base)),
+        ("/".to_string(), format!("{}/gone", base)),
+        ("/".to_string(), format!("{}/slow", base)),
+    ]);
+    let options = CheckOptions { concurrency: 4, slow_after: Duration::from_millis(150) };
+    let reports = check_links(&ReqwestClient::new(Duration::from_secs(5)), links, &options).await;
+    let find = |path: &str| reports.iter().find(|r| r.url.ends_with(path)).unwrap();
+
+    assert_eq!(reports.len(), 4);
+    assert_eq!(find("/ok").status, LinkStatus::Ok { status: 200 });
+    assert_eq!(find("/ok").sources, vec!["/", "/gaim"]);
+    assert_eq!(
+        find("/moved").status,
+        LinkStatus::Redirected { status: 301, location: Some("/ok".to_string()) }
+    );
+    assert!(matches!(find("/gone").status, LinkStatus::Dead { .. }));
+    assert!(find("/slow").slow);
+    assert!(!find("/ok").slow);
+
+    let report = report_json(&reports);
+    assert_eq!(report["checked"], 4);
+    assert_eq!(report["dead"], 1);
+    assert_eq!(report["redirected"], 1);
+    assert_eq!(report["slow"], 1);
+}
+
+#[tokio::test]
+async fn unreachable_hosts_are_dead() {
+    // Bind and drop to get a port nothing listens on
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let links = group_by_url(vec![("/".to_string(), format!("http://127.0.0.1:{}/", port))]);
+    let reports = check_links(&ReqwestClient::new(Duration::from_secs(5)), links, &CheckOptions::default()).await;
+    assert!(matches!(reports[0].status, LinkStatus::Dead { .. }));
+}
+
+#[test]
+fn collects_links_from_rendered_routes() {
+    let urls: Vec<String> = site_paths(Build::CURRENT).iter().flat_map(|p| extract_urls(&render_path(p))).collect();
+    assert!(urls.contains(&"https://github.com/Weiming-Hu".to_string()));
+    assert!(urls.contains(&"https://github.com/Weiming-Hu/PyPIOMAS".to_string()));
+    assert!(extract_urls(&render_path("/gaim/themes/arctic")).contains(&"https://github.com/Weiming-Hu/PyPIOMAS".to_string()));
+    assert!(urls.contains(&"https://scholar.google.com/citations?user=2xypOLMAAAAJ&hl".to_string()));
+}
@@ -0,0 +1,55 @@
+use personal_website::components::{render_markdown, render_path};
+
+// The `href`s in the rendered HTML
+fn hrefs(html: &str) -> Vec<&str> {
+    html.split("href=\"").skip(1).filter_map(|rest| rest.split('"').next()).collect()
+}
+
+#[test]
+fn unsafe_links_render_as_text() {
+    for url in ["javascript:alert(1)", "JAVASCRIPT:alert(1)", "JavaScript:alert(1)", "data:text/html,hi", "//evil.example", "vbscript:x", " javascript:alert(1)"] {
+        let html = render_markdown(&format!("[click](<{}>)", url));
+        assert!(hrefs(&html).is_empty(), "{:?} became a link: {}", url, html);
+        assert!(html.contains("click"), "{:?} lost its text: {}", url, html);
+    }
+}
+
+#[test]
+fn safe_links_survive_spaces_and_case() {
+    assert_eq!(hrefs(&render_markdown("[a](https://example.org)")), ["https://example.org"]);
+    assert_eq!(hrefs(&render_markdown("[a](< https://example.org>)")), ["https://example.org"]);
+    assert_eq!(hrefs(&render_markdown("[a](HTTPS://example.org)")), ["HTTPS://example.org"]);
+    assert_eq!(hrefs(&render_markdown("[a](mailto:lab@example.org)")), ["mailto:lab@example.org"]);
+    assert_eq!(hrefs(&render_markdown("[a](#top)")), ["#top"]);
+}
+
+#[test]
+fn raw_html_is_dropped() {
+    let html = render_markdown("Hello <script>alert(1)</script> <img src=x onerror=alert(1)> <b onclick=\"x\">there</b>");
+    for tag in ["<script", "<img", "onerror", "onclick", "<b "] {
+        assert!(!html.contains(tag), "{:?} kept: {}", tag, html);
+    }
+    assert!(html.contains("Hello") && html.contains("there"), "{}", html);
+
+    let block = render_markdown("<div onclick=\"x\">\nhidden\n</div>\n\nafter");
+    assert!(!block.contains("<div onclick"), "{}", block);
+    assert!(block.contains("after"), "{}", block);
+}
+
+#[test]
+fn lists_and_code_render() {
+    let html = render_markdown("- one\n- two\n\n1. first\n\nUse `cargo test`.\n\n```\nfn main() {}\n```");
+    assert_eq!(html.matches("<li>").count(), 3, "{}", html);
+    assert!(html.contains("<ul") && html.contains("<ol"), "{}", html);
+    assert!(html.contains(">cargo test</code>"), "{}", html);
+    assert!(html.contains("<pre") && html.contains("fn main() {}"), "{}", html);
+}
+
+#[test]
+fn site_links_go_through_the_router() {
+    // The recruiting note on Home links to the Lab page. A router link left outside the router renders nothing.
+    let html = render_path("/");
+    let link = html.split("<a ").find(|a| a.contains(">Lab for GAIM</a>")).expect("the recruiting link");
+    assert!(link.contains("href=\"/gaim\""), "{}", link);
+    assert!(!link.contains("target="), "{}", link);
+}
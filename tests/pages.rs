@@ -0,0 +1,56 @@
+use personal_website::components::{render_path, render_site, site_paths, sitemap, Build, Preloaded};
+
+// The page below the navigation bar
+fn body(path: &str) -> String {
+    below_header(render_path(path))
+}
+
+fn below_header(html: String) -> String {
+    html.split_once("</header>").map(|(_, body)| body.to_string()).unwrap_or(html)
+}
+
+#[test]
+fn every_listed_page_has_its_own_component() {
+    let home = body("/");
+    let pages: Vec<String> = site_paths(Build::CURRENT).into_iter().filter(|p| p.matches('/').count() == 1 && p != "/").collect();
+    assert!(pages.len() >= 10, "{:?}", pages);
+    for path in pages.iter().filter(|p| *p != "/home") {
+        assert_ne!(body(path), home, "{} shows the Home page", path);
+        assert!(!body(path).contains("Not Found"), "{} is not found", path);
+    }
+}
+
+#[test]
+fn unknown_pages_are_not_found() {
+    for path in ["/no-such-page", "/gaim/no/such/page", "/nothing/here"] {
+        assert!(body(path).contains("Not Found"), "{} is found", path);
+    }
+}
+
+#[test]
+fn hidden_pages_leave_release_builds() {
+    let release = |path: &str| render_site(path, Build::Release, Preloaded::default());
+    let paths = site_paths(Build::Release);
+    let map = sitemap("https://example.org", Build::Release);
+    for hidden in ["/talks", "/teaching"] {
+        // Debug builds keep them so they can be worked on
+        assert!(site_paths(Build::Debug).contains(&hidden.to_string()));
+        assert!(!below_header(render_site(hidden, Build::Debug, Preloaded::default())).contains("Not Found"));
+
+        assert!(!paths.contains(&hidden.to_string()), "{} is in the release paths", hidden);
+        assert!(!map.contains(&format!("https://example.org{}<", hidden)), "{} is in the sitemap", hidden);
+        // Neither the navigation nor any page links to it
+        for path in &paths {
+            assert!(!release(path).contains(&format!("href=\"{}\"", hidden)), "{} links to {}", path, hidden);
+        }
+        assert!(below_header(release(hidden)).contains("Not Found"), "{} is still routed", hidden);
+    }
+    assert!(map.contains("<loc>https://example.org/res</loc>"));
+    assert!(map.contains("<loc>https://example.org/</loc>"));
+}
+
+#[test]
+fn unknown_audiences_are_not_found() {
+    assert!(body("/res/nobody").contains("Not Found"));
+    assert!(!body("/res/grad").contains("Not Found"));
+}
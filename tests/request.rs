@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::America::New_York;
+use personal_website::components::ics::Interval;
+use personal_website::components::request::{Field, MeetingMode, MeetingRequest, RequestSettings};
+
+fn utc(s: &str) -> DateTime<Utc> {
+    s.parse().unwrap()
+}
+
+fn settings() -> RequestSettings {
+    RequestSettings {
+        to: "pi@example.edu".to_string(),
+        in_person: "GGY 312".to_string(),
+        video: "Video call".to_string(),
+    }
+}
+
+fn request() -> MeetingRequest {
+    MeetingRequest {
+        name: "Ada Lovelace".to_string(),
+        email: "ada@example.org".to_string(),
+        topic: "Sea ice, models; and data".to_string(),
+        agenda: "1. Data\n2. Next steps".to_string(),
+        mode: MeetingMode::InPerson,
+        slots: vec![
+            Interval { start: utc("2025-03-10T14:00:00Z"), end: utc("2025-03-10T14:30:00Z") },
+            Interval { start: utc("2025-03-11T18:00:00Z"), end: utc("2025-03-11T18:30:00Z") },
+        ],
+        other_times: String::new(),
+    }
+}
+
+#[test]
+fn complete_request_has_no_problems() {
+    assert!(request().problems().is_empty());
+}
+
+#[test]
+fn problems_name_the_fields() {
+    let incomplete = MeetingRequest {
+        name: " ".to_string(),
+        email: "ada@localhost".to_string(),
+        topic: String::new(),
+        slots: Vec::new(),
+        ..request()
+    };
+    let fields: Vec<Field> = incomplete.problems().iter().map(|p| p.field).collect();
+    assert_eq!(fields, [Field::Name, Field::Email, Field::Topic, Field::Times]);
+
+    // Free text stands in for picked times
+    let unpicked = MeetingRequest { slots: Vec::new(), other_times: "Any Friday morning".to_string(), ..request() };
+    assert!(unpicked.problems().is_empty());
+    assert!(unpicked.to_ics(&settings(), New_York, Utc::now()).is_none());
+}
+
+#[test]
+fn long_topics_are_told_the_limit() {
+    let long = MeetingRequest { topic: "x".repeat(121), ..request() };
+    let messages: Vec<String> = long.problems().into_iter().map(|p| p.message).collect();
+    assert_eq!(messages, ["The topic is too long. Please keep it to 120 characters."]);
+}
+
+#[test]
+fn invite_is_valid_icalendar() {
+    let ics = request().to_ics(&settings(), New_York, utc("2025-03-01T12:00:00Z")).unwrap();
+    assert!(ics.lines().count() > 10);
+    assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    assert!(!ics.replace("\r\n", "").contains('\n'));
+    let unfolded = ics.replace("\r\n ", "");
+    for line in [
+        "METHOD:REQUEST",
+        "DTSTART:20250310T140000Z",
+        "DTEND:20250310T143000Z",
+        "DTSTAMP:20250301T120000Z",
+        r"SUMMARY:Meeting request: Sea ice\, models\; and data",
+        "LOCATION:GGY 312",
+        "ORGANIZER;CN=\"Ada Lovelace\":mailto:ada@example.org",
+        "ATTENDEE;ROLE=REQ-PARTICIPANT;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:pi@example.edu",
+    ] {
+        assert!(unfolded.split("\r\n").any(|l| l == line), "missing {line}");
+    }
+    // The second choice is listed in lab time, which is EDT by then
+    assert!(unfolded.contains("2. Tue\\, Mar 11\\, 2025 14:00–14:30 (America/New_York)"));
+    assert!(unfolded.contains("Agenda:\\n1. Data\\n2. Next steps"));
+}
+
+#[test]
+fn mailto_is_encoded() {
+    let video = MeetingRequest { mode: MeetingMode::Video, ..request() };
+    let mailto = video.mailto(&settings(), New_York);
+    assert!(mailto.starts_with("mailto:pi@example.edu?subject=Meeting%20request%3A%20Sea%20ice%2C%20models%3B%20and%20data&body="));
+    let body = mailto.split("&body=").nth(1).unwrap();
+    assert!(!body.contains(['&', ' ', '\n', '?']));
+    assert!(body.contains("Meeting%3A%20Video%20call%20%28Video%20call%29"));
+    assert!(body.contains("1.%20Mon%2C%20Mar%2010%2C%202025%2010%3A00"));
+}
@@ -0,0 +1,149 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use personal_website::components::ics::Interval;
+use personal_website::components::rules::MeetingRules;
+
+fn utc(s: &str) -> DateTime<Utc> {
+    s.parse().unwrap()
+}
+
+fn interval(start: &str, end: &str) -> Interval {
+    Interval { start: utc(start), end: utc(end) }
+}
+
+fn rules() -> MeetingRules {
+    toml::from_str(
+        r#"
+        timezone = "America/New_York"
+        slot_minutes = 30
+        buffer_minutes = 15
+        blackout_dates = ["2025-03-12"]
+
+        [[slot_types]]
+        id = "office"
+        label = "Office hours"
+        location = "GGY 312"
+
+        [[slot_types]]
+        id = "video"
+        label = "Video calls"
+        location = "Zoom"
+
+        [[windows]]
+        days = ["mon", "wed"]
+        start = "10:00"
+        end = "12:00"
+        slot_type = "office"
+
+        [[windows]]
+        days = ["fri"]
+        start = "14:00"
+        end = "15:00"
+        slot_type = "video"
+        "#,
+    )
+    .unwrap()
+}
+
+fn starts(rules: &MeetingRules, busy: &[Interval], range: Interval) -> Vec<(DateTime<Utc>, String)> {
+    rules.slots(busy, range).into_iter().map(|s| (s.interval.start, s.slot_type)).collect()
+}
+
+#[test]
+fn slots_follow_the_weekly_windows_in_the_lab_zone() {
+    // Week of March 3, 2025, before DST: New York is UTC-5
+    let slots = starts(&rules(), &[], interval("2025-03-03T00:00:00Z", "2025-03-08T00:00:00Z"));
+    let office = |day: &str, hour: &str| (utc(&format!("2025-03-{}T{}Z", day, hour)), "office".to_string());
+    let video = |day: &str, hour: &str| (utc(&format!("2025-03-{}T{}Z", day, hour)), "video".to_string());
+    assert_eq!(
+        slots,
+        vec![
+            office("03", "15:00:00"),
+            office("03", "15:30:00"),
+            office("03", "16:00:00"),
+            office("03", "16:30:00"),
+            office("05", "15:00:00"),
+            office("05", "15:30:00"),
+            office("05", "16:00:00"),
+            office("05", "16:30:00"),
+            video("07", "19:00:00"),
+            video("07", "19:30:00"),
+        ]
+    );
+}
+
+#[test]
+fn busy_times_block_slots_with_a_buffer() {
+    // Monday March 10, after DST: 10:00 New York is 14:00 UTC. Busy 10:40-11:00 local.
+    let busy = [interval("2025-03-10T14:40:00Z", "2025-03-10T15:00:00Z")];
+    let slots = starts(&rules(), &busy, interval("2025-03-10T00:00:00Z", "2025-03-11T00:00:00Z"));
+    let times: Vec<DateTime<Utc>> = slots.into_iter().map(|(start, _)| start).collect();
+    // 10:00 ends at 10:30, within 15 minutes of 10:40; 11:00 starts right after; only 11:30 is clear
+    assert_eq!(times, vec![utc("2025-03-10T15:30:00Z")]);
+}
+
+#[test]
+fn blackout_dates_and_the_range_are_respected() {
+    // Wednesday March 12 is a blackout date
+    assert!(starts(&rules(), &[], interval("2025-03-12T00:00:00Z", "2025-03-13T00:00:00Z")).is_empty());
+    // Slots that have started are not offered. Friday 14:00 New York is 18:00 UTC after DST.
+    let slots = starts(&rules(), &[], interval("2025-03-14T18:15:00Z", "2025-03-15T00:00:00Z"));
+    assert_eq!(slots, vec![(utc("2025-03-14T18:30:00Z"), "video".to_string())]);
+}
+
+#[test]
+fn rules_are_described_in_sentences() {
+    let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    assert_eq!(
+        rules().describe(today),
+        vec![
+            "Meetings are 30 minutes long.",
+            "Office hours: Mon, Wed 10:00–12:00 (America/New_York time), GGY 312.",
+            "Video calls: Fri 14:00–15:00 (America/New_York time), Zoom.",
+            "I keep 15 minutes free before and after other commitments.",
+            "No meetings on Mar 12, 2025.",
+        ]
+    );
+    // Past blackout dates are not mentioned
+    let later = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+    assert_eq!(rules().describe(later).len(), 4);
+}
+
+#[test]
+fn windows_starting_in_a_dst_gap_start_after_the_change() {
+    let rules: MeetingRules = toml::from_str(
+        r#"
+        timezone = "America/New_York"
+        slot_minutes = 30
+
+        [[slot_types]]
+        id = "early"
+        label = "Early calls"
+        location = "Phone"
+
+        [[windows]]
+        days = ["sun"]
+        start = "02:00"
+        end = "04:00"
+        slot_type = "early"
+        "#,
+    )
+    .unwrap();
+    // 02:00 does not exist on March 9, 2025 in New York; the window opens at 03:00 EDT
+    let slots = starts(&rules, &[], interval("2025-03-09T00:00:00Z", "2025-03-10T00:00:00Z"));
+    let times: Vec<DateTime<Utc>> = slots.into_iter().map(|(start, _)| start).collect();
+    assert_eq!(times, vec![utc("2025-03-09T07:00:00Z"), utc("2025-03-09T07:30:00Z")]);
+}
+
+#[test]
+fn unusable_zones_and_lengths_are_rejected() {
+    let read = |timezone: &str, minutes: i64| {
+        toml::from_str::<MeetingRules>(&format!(
+            "timezone = \"{}\"\nslot_minutes = {}\nwindows = []\nslot_types = []\n",
+            timezone, minutes
+        ))
+        .map_err(|e| e.to_string())
+    };
+    assert!(read("America/New_York", 30).is_ok());
+    assert!(read("America/Gotham", 30).unwrap_err().contains("unknown timezone \"America/Gotham\""));
+    assert!(read("America/New_York", 0).unwrap_err().contains("slot_minutes must be at least 1"));
+}
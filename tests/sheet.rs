@@ -0,0 +1,95 @@
+use chrono::NaiveDate;
+use personal_website::components::sheet::{parse_expiration, parse_recurrence, parse_sheet, sheet_warnings, Expiration, Recurrence};
+
+fn date(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+#[test]
+fn expirations_read_every_listed_format() {
+    for cell in ["2025/03/01", "2025-03-01", "03/01/2025", "2025.03.01", "Mar 01, 2025", "March 1, 2025", "1 Mar 2025", " 1 march 2025 "] {
+        assert_eq!(parse_expiration(cell), Expiration::Date(date("2025-03-01")), "{:?}", cell);
+    }
+}
+
+#[test]
+fn blank_and_worded_expirations() {
+    assert_eq!(parse_expiration(""), Expiration::Never);
+    assert_eq!(parse_expiration("  "), Expiration::Never);
+    assert_eq!(parse_expiration("Never"), Expiration::Never);
+    assert_eq!(parse_expiration("Rolling"), Expiration::Rolling);
+    assert_eq!(parse_expiration("open until filled"), Expiration::Rolling);
+    assert_eq!(parse_expiration("TBD"), Expiration::Unknown);
+}
+
+#[test]
+fn malformed_expirations_keep_the_cell() {
+    assert_eq!(parse_expiration("next spring"), Expiration::Invalid("next spring".to_string()));
+    assert_eq!(parse_expiration("2025-02-30"), Expiration::Invalid("2025-02-30".to_string()));
+    assert_eq!(parse_expiration("13/01/2025"), Expiration::Invalid("13/01/2025".to_string()));
+}
+
+#[test]
+fn warnings_name_the_sheet_line_and_cell() {
+    let sheet = parse_sheet(include_str!("fixtures/resources.csv"));
+    assert_eq!(sheet.rows.len(), 5);
+    let warnings: Vec<(usize, String)> = sheet_warnings(&sheet).into_iter().map(|w| (w.line, w.problem)).collect();
+    // The grant's caption spans two lines, so the rows after it start one line later
+    assert_eq!(
+        warnings,
+        [
+            (6, "unreadable expiration \"next spring\"".to_string()),
+            (6, "unreadable recurrence \"sometime\"".to_string()),
+            (6, "unknown category \"Seminar\"".to_string()),
+            (6, "unknown audience \"alumni\"".to_string()),
+            (8, "has 2 cells where the header has 7".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn recurrences_read_every_listed_pattern() {
+    assert_eq!(parse_recurrence(""), None);
+    for cell in ["yearly", "Annually", "annual", "every year"] {
+        assert_eq!(parse_recurrence(cell), Some(Recurrence::Yearly), "{:?}", cell);
+    }
+    for cell in ["02/29", "02-29", "Feb 29", "February 29", " february 29 "] {
+        assert_eq!(parse_recurrence(cell), Some(Recurrence::OnDay { month: 2, day: 29 }), "{:?}", cell);
+    }
+    for cell in ["monthly", "13/01", "Feb 30"] {
+        assert_eq!(parse_recurrence(cell), Some(Recurrence::Invalid(cell.to_string())), "{:?}", cell);
+    }
+}
+
+#[test]
+fn fixed_days_roll_into_the_next_year() {
+    let jan = Recurrence::OnDay { month: 1, day: 15 };
+    assert_eq!(jan.next_occurrence(&Expiration::Never, date("2025-12-20")), Some(date("2026-01-15")));
+    assert_eq!(jan.next_occurrence(&Expiration::Never, date("2025-01-15")), Some(date("2025-01-15")));
+    let end_of_month = Recurrence::OnDay { month: 3, day: 31 };
+    assert_eq!(end_of_month.next_occurrence(&Expiration::Never, date("2025-03-01")), Some(date("2025-03-31")));
+    assert_eq!(end_of_month.next_occurrence(&Expiration::Never, date("2025-04-01")), Some(date("2026-03-31")));
+}
+
+#[test]
+fn leap_days_fall_back_to_february_28() {
+    let leap = Recurrence::OnDay { month: 2, day: 29 };
+    assert_eq!(leap.next_occurrence(&Expiration::Never, date("2025-01-10")), Some(date("2025-02-28")));
+    assert_eq!(leap.next_occurrence(&Expiration::Never, date("2027-03-01")), Some(date("2028-02-29")));
+}
+
+#[test]
+fn yearly_follows_the_expiration_date() {
+    let expired = Expiration::Date(date("2024-12-31"));
+    assert_eq!(Recurrence::Yearly.next_occurrence(&expired, date("2025-01-02")), Some(date("2025-12-31")));
+    let upcoming = Expiration::Date(date("2026-05-01"));
+    assert_eq!(Recurrence::Yearly.next_occurrence(&upcoming, date("2025-01-02")), Some(date("2026-05-01")));
+    assert_eq!(Recurrence::Yearly.next_occurrence(&Expiration::Rolling, date("2025-01-02")), None);
+}
+
+#[test]
+fn invalid_recurrences_keep_the_expiration() {
+    let invalid = Recurrence::Invalid("monthly".to_string());
+    let expiration = Expiration::Date(date("2024-06-01"));
+    assert_eq!(invalid.next_occurrence(&expiration, date("2025-01-02")), Some(date("2024-06-01")));
+}
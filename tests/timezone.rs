@@ -0,0 +1,53 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::America::{New_York, Toronto};
+use chrono_tz::Europe::London;
+use personal_website::components::availability::week;
+use personal_website::components::timezone::{deadline, offset_label, resolve, same_clock, with_lab_time};
+
+fn utc(s: &str) -> DateTime<Utc> {
+    s.parse().unwrap()
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn skipped_and_repeated_times_resolve() {
+    // 02:30 does not exist on the morning clocks spring forward
+    assert_eq!(resolve(&New_York, date(2025, 3, 9).and_hms_opt(2, 30, 0).unwrap()), utc("2025-03-09T07:30:00Z"));
+    // 01:30 happens twice when they fall back; the first is still daylight time
+    assert_eq!(resolve(&New_York, date(2025, 11, 2).and_hms_opt(1, 30, 0).unwrap()), utc("2025-11-02T05:30:00Z"));
+    assert_eq!(resolve(&London, date(2025, 3, 30).and_hms_opt(1, 15, 0).unwrap()), utc("2025-03-30T01:15:00Z"));
+}
+
+#[test]
+fn deadlines_close_at_the_end_of_the_lab_day() {
+    assert_eq!(deadline(date(2025, 1, 15)), utc("2025-01-16T04:59:00Z"));
+    assert_eq!(deadline(date(2025, 3, 9)), utc("2025-03-10T03:59:00Z"));
+    assert_eq!(deadline(date(2025, 11, 2)), utc("2025-11-03T04:59:00Z"));
+}
+
+#[test]
+fn lab_time_is_added_only_when_clocks_differ() {
+    // The US has changed its clocks and the UK has not, so they are four hours apart
+    let noon = utc("2025-03-20T12:00:00Z");
+    assert_eq!(
+        with_lab_time(noon, London, "%b %-d, %-I:%M %p"),
+        "Mar 20, 12:00 PM GMT (Mar 20, 8:00 AM EDT in the lab)"
+    );
+    assert_eq!(with_lab_time(noon, Toronto, "%H:%M"), "08:00 EDT");
+    assert!(same_clock(noon, Toronto, New_York));
+    assert!(!same_clock(noon, London, New_York));
+    assert_eq!(offset_label(noon, London), "UTC+00:00");
+    assert_eq!(offset_label(noon, New_York), "UTC-04:00");
+}
+
+#[test]
+fn week_view_days_follow_the_visitors_clock_changes() {
+    let days = week(&[], date(2025, 3, 3), &New_York, (2, 4));
+    assert_eq!(days[0].window.start, utc("2025-03-03T07:00:00Z"));
+    // On Sunday 02:00 is skipped, so the day starts at 03:00 daylight time
+    assert_eq!(days[6].window.start, utc("2025-03-09T07:00:00Z"));
+    assert_eq!(days[6].window.end, utc("2025-03-09T08:00:00Z"));
+}